@@ -1,20 +1,53 @@
 //! Benchmark for ZK-SNARK proving time
 //!
-//! Target: <5 seconds for all circuit types
+//! Target: <5 seconds for all circuit types. This target is only tracked for BN254, the
+//! only curve `ThresholdCircuit`/`RangeCircuit`/`TierCircuit` support today;
+//! `bench_bls12_377_square_proving` below benchmarks an unrelated toy circuit and does not
+//! track this target for any compliance circuit.
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
 use civium_zk_prover::{
     circuits::{ThresholdCircuit, RangeCircuit, TierCircuit},
+    proof::{Proof, ProofWithPublicInputs},
     types::{ThresholdInput, RangeInput, TierInput},
+    verify_batch,
 };
+use ark_bls12_377::{Bls12_377, Fr as Bls12_377Fr};
 use ark_bn254::{Bn254, Fr};
+use ark_ff::Field;
 use ark_groth16::Groth16;
+use ark_r1cs_std::fields::fp::FpVar;
+use ark_r1cs_std::prelude::*;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
 use ark_snark::SNARK;
 use ark_std::rand::thread_rng;
 use std::time::Duration;
 
 const TARGET_TIME_SECS: u64 = 5;
 
+/// Trivial curve-agnostic circuit (`public_y == private_x * private_x`), used to exercise
+/// Groth16 proving over a non-BN254 curve without needing a curve-specific Poseidon
+/// configuration for every instantiation.
+///
+/// This crate's real compliance circuits (`ThresholdCircuit`/`RangeCircuit`/`TierCircuit`)
+/// aren't generic over the curve yet - only the `Proof<E>`/`ProofWithPublicInputs<E>`
+/// serialization wrappers are - so this benchmark stands in for them to measure proving
+/// time on BLS12-377 rather than claiming those circuits run on it today.
+#[derive(Clone)]
+struct SquareCircuit<F: Field> {
+    y: F,
+    x: F,
+}
+
+impl<F: ark_ff::PrimeField> ConstraintSynthesizer<F> for SquareCircuit<F> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let y_var = FpVar::new_input(cs.clone(), || Ok(self.y))?;
+        let x_var = FpVar::new_witness(cs, || Ok(self.x))?;
+        (&x_var * &x_var).enforce_equal(&y_var)?;
+        Ok(())
+    }
+}
+
 /// Benchmark threshold circuit proving
 fn bench_threshold_proving(c: &mut Criterion) {
     let mut group = c.benchmark_group("threshold_proving");
@@ -163,12 +196,87 @@ fn bench_verification(c: &mut Criterion) {
     group.finish();
 }
 
+/// Compare per-proof vs. batched verification latency across batch sizes.
+fn bench_batch_verification(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch_verification");
+    group.measurement_time(Duration::from_secs(20));
+
+    let entity_hash = Fr::from(123456789012345678u64);
+    let salt = Fr::from(987654321098765432u64);
+    let circuit = ThresholdCircuit::new(8000, entity_hash, 8500, salt);
+
+    let mut rng = thread_rng();
+    let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(circuit.clone(), &mut rng).unwrap();
+
+    let batch_sizes = [1usize, 8, 32, 64];
+    for &size in &batch_sizes {
+        let proofs: Vec<ProofWithPublicInputs> = (0..size)
+            .map(|_| {
+                let circuit = circuit.clone();
+                let public_inputs = vec![circuit.threshold, circuit.entity_hash, circuit.commitment];
+                let inner = Groth16::<Bn254>::prove(&pk, circuit, &mut rng).unwrap();
+                ProofWithPublicInputs::new(Proof::new(inner), public_inputs, "threshold".into())
+            })
+            .collect();
+
+        group.bench_with_input(BenchmarkId::new("per_proof", size), &proofs, |b, proofs| {
+            b.iter(|| {
+                for proof in black_box(proofs) {
+                    Groth16::<Bn254>::verify(&vk, &proof.public_inputs, &proof.proof.inner).unwrap();
+                }
+            });
+        });
+
+        group.bench_with_input(BenchmarkId::new("batched", size), &proofs, |b, proofs| {
+            b.iter(|| {
+                let failed = verify_batch(&vk, black_box(proofs)).unwrap();
+                assert!(failed.is_empty());
+            });
+        });
+    }
+
+    group.finish();
+}
+
+/// Benchmark proving a trivial curve-agnostic circuit over BLS12-377.
+///
+/// This is *not* one of the `<5s`-tracked compliance circuits - `ThresholdCircuit`/
+/// `RangeCircuit`/`TierCircuit` aren't generic over the curve (see `SquareCircuit`'s doc
+/// comment above), so there is no per-curve proving-time target to track for them yet.
+/// This benchmark only measures `Groth16::<Bls12_377>` proving of this toy circuit; it
+/// says nothing about this crate's actual compliance proofs.
+fn bench_bls12_377_square_proving(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bls12_377_square_proving");
+    group.measurement_time(Duration::from_secs(30));
+    group.sample_size(10);
+
+    let circuit = SquareCircuit {
+        y: Bls12_377Fr::from(8500u64) * Bls12_377Fr::from(8500u64),
+        x: Bls12_377Fr::from(8500u64),
+    };
+
+    group.bench_function("square", |b| {
+        let mut rng = thread_rng();
+
+        let (pk, _vk) = Groth16::<Bls12_377>::circuit_specific_setup(circuit.clone(), &mut rng).unwrap();
+
+        b.iter(|| {
+            let circuit = black_box(circuit.clone());
+            let _proof = Groth16::<Bls12_377>::prove(&pk, circuit, &mut rng).unwrap();
+        });
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_threshold_proving,
     bench_range_proving,
     bench_tier_proving,
     bench_verification,
+    bench_batch_verification,
+    bench_bls12_377_square_proving,
 );
 
 criterion_main!(benches);
@@ -0,0 +1,1018 @@
+//! Bulletproofs range-proof backend
+//!
+//! An alternative to the Groth16 [`crate::circuits::RangeCircuit`] for proving
+//! `min_score <= score <= max_score`: a logarithmic-size inner-product range proof with
+//! **no circuit-specific trusted setup**, only a fixed, public set of Pedersen generators
+//! shared by every proof (see [`BulletproofGens`]).
+//!
+//! To prove a value `v` is in `[0, 2^n)`, the prover commits to `v`'s bits `a_L` and their
+//! complement `a_R = a_L - 1^n`, blinds them, and derives Fiat-Shamir challenges `y`, `z`
+//! that fold the two constraints `a_L ∘ a_R = 0` and `a_L - a_R - 1^n = 0` into a single
+//! inner-product relation `t(x) = <l(x), r(x)>`. That relation is then compressed by a
+//! recursive inner-product argument that halves the witness vectors each round, giving a
+//! proof of size `O(log n)` rather than `O(n)`. Because [`crate::types::RangeInput`]'s
+//! bound doesn't start at zero, [`BulletproofRangeProof`] proves the two derived values
+//! `score - min_score` and `max_score - score` are each in `[0, 2^n)`.
+//!
+//! The generators here are derived with a try-and-increment hash-to-curve (see
+//! [`derive_generator`]) rather than by scaling a known base point, so no party can compute
+//! a discrete log relating `g`/`h`/`g_vec`/`h_vec` to each other or to the BN254 generator -
+//! the property Pedersen-commitment binding depends on.
+
+use ark_bn254::{Fq, Fr, G1Affine, G1Projective};
+use ark_ec::short_weierstrass::SWCurveConfig;
+use ark_ec::{CurveGroup, Group};
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{rand::thread_rng, UniformRand, Zero};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ProverError, Result};
+use crate::poseidon::PoseidonHasher;
+use crate::types::RangeInput;
+
+/// Bit-width of each bounded value proven in range. `MAX_SCORE` fits in 14 bits, but the
+/// protocol requires a power of two, so 32 bits gives headroom without changing shape.
+pub const RANGE_BITS: usize = 32;
+
+/// Public, deterministically-derived Pedersen generators shared by every Bulletproof in
+/// this module. `g`/`h` commit to a single value and its blinding factor; `g_vec`/`h_vec`
+/// (one pair per bit) commit to the bit-decomposition vectors `a_L`/`a_R`.
+#[derive(Clone)]
+pub struct BulletproofGens {
+    /// Value generator
+    pub g: G1Affine,
+    /// Blinding generator
+    pub h: G1Affine,
+    /// Per-bit generators for the left bit-vector commitment
+    pub g_vec: Vec<G1Affine>,
+    /// Per-bit generators for the right bit-vector commitment
+    pub h_vec: Vec<G1Affine>,
+}
+
+impl BulletproofGens {
+    /// Build the generator set for vectors of length `n`.
+    pub fn new(n: usize) -> Self {
+        Self {
+            g: derive_generator("civium/bulletproofs/g", 0),
+            h: derive_generator("civium/bulletproofs/h", 0),
+            g_vec: (0..n).map(|i| derive_generator("civium/bulletproofs/g_vec", i as u64)).collect(),
+            h_vec: (0..n).map(|i| derive_generator("civium/bulletproofs/h_vec", i as u64)).collect(),
+        }
+    }
+}
+
+/// Derive a nothing-up-my-sleeve generator via try-and-increment hash-to-curve: hash
+/// `label`, `index`, and an increasing counter with Poseidon, reduce the digest into a
+/// BN254 base-field element, and accept the first one that's a valid curve x-coordinate.
+/// BN254 G1 has cofactor 1, so every point on the curve already sits in the prime-order
+/// subgroup - no cofactor clearing needed. Nobody knows a scalar relating the result to
+/// the BN254 generator or to any other generator this function produces, which is what
+/// makes the resulting Pedersen commitments binding (see the module docs).
+fn derive_generator(label: &str, index: u64) -> G1Affine {
+    let seed = label.bytes().fold(0u64, |acc, b| acc.wrapping_mul(31).wrapping_add(b as u64));
+    let hasher = PoseidonHasher::new();
+
+    let mut counter = 0u64;
+    loop {
+        let digest = hasher.hash(&[Fr::from(seed), Fr::from(index), Fr::from(counter)]);
+        let x = Fq::from_le_bytes_mod_order(&digest.into_bigint().to_bytes_le());
+        if let Some(point) = ark_bn254::g1::Config::get_point_from_x_unchecked(x, true) {
+            return point;
+        }
+        counter += 1;
+    }
+}
+
+/// Fiat-Shamir transcript built on the same Poseidon sponge used elsewhere in this crate.
+/// Every absorbed value (points are absorbed by their x-coordinate, reduced into `Fr`)
+/// extends the running state, so each challenge is bound to everything absorbed so far.
+struct Transcript {
+    hasher: PoseidonHasher,
+    state: Vec<Fr>,
+}
+
+impl Transcript {
+    fn new(domain: &str) -> Self {
+        let seed = domain.bytes().fold(0u64, |acc, b| acc.wrapping_mul(31).wrapping_add(b as u64));
+        Self {
+            hasher: PoseidonHasher::new(),
+            state: vec![Fr::from(seed)],
+        }
+    }
+
+    fn append_scalar(&mut self, s: Fr) {
+        self.state.push(s);
+    }
+
+    fn append_point(&mut self, p: &G1Affine) {
+        let x_bytes = p.x.into_bigint().to_bytes_le();
+        self.state.push(Fr::from_le_bytes_mod_order(&x_bytes));
+    }
+
+    fn challenge(&mut self) -> Fr {
+        let c = self.hasher.hash(&self.state);
+        self.state.push(c);
+        c
+    }
+}
+
+fn inner_product(a: &[Fr], b: &[Fr]) -> Fr {
+    a.iter().zip(b.iter()).map(|(x, y)| *x * y).sum()
+}
+
+fn hadamard(a: &[Fr], b: &[Fr]) -> Vec<Fr> {
+    a.iter().zip(b.iter()).map(|(x, y)| *x * y).collect()
+}
+
+fn scalar_vec_add(a: &[Fr], b: &[Fr]) -> Vec<Fr> {
+    a.iter().zip(b.iter()).map(|(x, y)| *x + y).collect()
+}
+
+fn powers(base: Fr, n: usize) -> Vec<Fr> {
+    let mut out = Vec::with_capacity(n);
+    let mut cur = Fr::from(1u64);
+    for _ in 0..n {
+        out.push(cur);
+        cur *= base;
+    }
+    out
+}
+
+fn multiscalar_mul(scalars: &[Fr], points: &[G1Affine]) -> G1Projective {
+    scalars
+        .iter()
+        .zip(points.iter())
+        .fold(G1Projective::zero(), |acc, (s, p)| acc + p.mul_bigint(s.into_bigint()))
+}
+
+/// Bit-decompose `v` into `n` field elements, least-significant bit first.
+fn bit_vector(v: u64, n: usize) -> Vec<Fr> {
+    (0..n).map(|i| Fr::from((v >> i) & 1)).collect()
+}
+
+/// Recursive inner-product argument, halving the witness vectors each round.
+///
+/// Returns the per-round `(L, R)` commitments and the final single-element witnesses
+/// `a`, `b` once the vectors have folded down to length 1.
+fn ipa_prove(
+    transcript: &mut Transcript,
+    mut g: Vec<G1Affine>,
+    mut h: Vec<G1Affine>,
+    u: G1Affine,
+    mut a: Vec<Fr>,
+    mut b: Vec<Fr>,
+) -> (Vec<G1Affine>, Vec<G1Affine>, Fr, Fr) {
+    let mut l_vec = Vec::new();
+    let mut r_vec = Vec::new();
+
+    while a.len() > 1 {
+        let n = a.len() / 2;
+
+        let c_l = inner_product(&a[..n], &b[n..]);
+        let c_r = inner_product(&a[n..], &b[..n]);
+
+        let l = multiscalar_mul(&a[..n], &g[n..])
+            + multiscalar_mul(&b[n..], &h[..n])
+            + u.mul_bigint(c_l.into_bigint());
+        let r = multiscalar_mul(&a[n..], &g[..n])
+            + multiscalar_mul(&b[..n], &h[n..])
+            + u.mul_bigint(c_r.into_bigint());
+
+        let l_aff = l.into_affine();
+        let r_aff = r.into_affine();
+        transcript.append_point(&l_aff);
+        transcript.append_point(&r_aff);
+        let x = transcript.challenge();
+        let x_inv = x.inverse().expect("challenge is never zero with overwhelming probability");
+
+        g = (0..n)
+            .map(|i| (g[i].mul_bigint(x_inv.into_bigint()) + g[n + i].mul_bigint(x.into_bigint())).into_affine())
+            .collect();
+        h = (0..n)
+            .map(|i| (h[i].mul_bigint(x.into_bigint()) + h[n + i].mul_bigint(x_inv.into_bigint())).into_affine())
+            .collect();
+        a = (0..n).map(|i| a[i] * x + a[n + i] * x_inv).collect();
+        b = (0..n).map(|i| b[i] * x_inv + b[n + i] * x).collect();
+
+        l_vec.push(l_aff);
+        r_vec.push(r_aff);
+    }
+
+    (l_vec, r_vec, a[0], b[0])
+}
+
+/// Replay the verifier's side of the same folding, given the challenges recovered from
+/// `transcript`, to recompute the final generators `g'`, `h'` and accumulate the `L`/`R`
+/// contribution to the committed point `P`.
+fn ipa_verify(
+    transcript: &mut Transcript,
+    mut g: Vec<G1Affine>,
+    mut h: Vec<G1Affine>,
+    mut p: G1Projective,
+    l_vec: &[G1Affine],
+    r_vec: &[G1Affine],
+) -> (G1Affine, G1Affine, G1Projective) {
+    for (l, r) in l_vec.iter().zip(r_vec.iter()) {
+        transcript.append_point(l);
+        transcript.append_point(r);
+        let x = transcript.challenge();
+        let x_inv = x.inverse().expect("challenge is never zero with overwhelming probability");
+        let x_sq = x * x;
+        let x_inv_sq = x_inv * x_inv;
+
+        let n = g.len() / 2;
+        g = (0..n)
+            .map(|i| (g[i].mul_bigint(x_inv.into_bigint()) + g[n + i].mul_bigint(x.into_bigint())).into_affine())
+            .collect();
+        h = (0..n)
+            .map(|i| (h[i].mul_bigint(x.into_bigint()) + h[n + i].mul_bigint(x_inv.into_bigint())).into_affine())
+            .collect();
+
+        p += l.mul_bigint(x_sq.into_bigint()) + r.mul_bigint(x_inv_sq.into_bigint());
+    }
+
+    (g[0], h[0], p)
+}
+
+/// A single `v in [0, 2^RANGE_BITS)` Bulletproof: the Pedersen commitment to `v`, and the
+/// proof that its committed value is non-negative and fits in `RANGE_BITS` bits.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SingleRangeProof {
+    /// Pedersen commitment `V = v*G + gamma*H` to the proven value
+    pub commitment: PointBytes,
+    a: PointBytes,
+    s: PointBytes,
+    t1: PointBytes,
+    t2: PointBytes,
+    t_hat: ScalarBytes,
+    tau_x: ScalarBytes,
+    mu: ScalarBytes,
+    l_vec: Vec<PointBytes>,
+    r_vec: Vec<PointBytes>,
+    a_final: ScalarBytes,
+    b_final: ScalarBytes,
+}
+
+/// A compressed G1 point, serialized with the rest of this crate's `ark-serialize`
+/// conventions (see [`crate::proof::Proof::to_bytes`]).
+pub type PointBytes = Vec<u8>;
+/// A compressed scalar field element.
+pub type ScalarBytes = Vec<u8>;
+
+fn point_to_bytes(p: &G1Affine) -> Result<PointBytes> {
+    let mut bytes = Vec::new();
+    p.serialize_compressed(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn point_from_bytes(bytes: &[u8]) -> Result<G1Affine> {
+    Ok(G1Affine::deserialize_compressed(bytes)?)
+}
+
+fn scalar_to_bytes(s: &Fr) -> Result<ScalarBytes> {
+    let mut bytes = Vec::new();
+    s.serialize_compressed(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn scalar_from_bytes(bytes: &[u8]) -> Result<Fr> {
+    Ok(Fr::deserialize_compressed(bytes)?)
+}
+
+impl SingleRangeProof {
+    /// Prove that `value` is in `[0, 2^RANGE_BITS)` under the Pedersen commitment
+    /// `value*gens.g + blinding*gens.h`.
+    fn prove(value: u64, blinding: Fr, gens: &BulletproofGens) -> Result<Self> {
+        let n = RANGE_BITS;
+        let mut rng = thread_rng();
+
+        let commitment = (gens.g.mul_bigint(Fr::from(value).into_bigint())
+            + gens.h.mul_bigint(blinding.into_bigint()))
+        .into_affine();
+
+        let a_l = bit_vector(value, n);
+        let a_r: Vec<Fr> = a_l.iter().map(|b| *b - Fr::from(1u64)).collect();
+
+        let alpha = Fr::rand(&mut rng);
+        let a_commit = (gens.h.mul_bigint(alpha.into_bigint())
+            + multiscalar_mul(&a_l, &gens.g_vec)
+            + multiscalar_mul(&a_r, &gens.h_vec))
+        .into_affine();
+
+        let s_l: Vec<Fr> = (0..n).map(|_| Fr::rand(&mut rng)).collect();
+        let s_r: Vec<Fr> = (0..n).map(|_| Fr::rand(&mut rng)).collect();
+        let rho = Fr::rand(&mut rng);
+        let s_commit = (gens.h.mul_bigint(rho.into_bigint())
+            + multiscalar_mul(&s_l, &gens.g_vec)
+            + multiscalar_mul(&s_r, &gens.h_vec))
+        .into_affine();
+
+        let mut transcript = Transcript::new("civium/bulletproofs/range");
+        transcript.append_point(&commitment);
+        transcript.append_point(&a_commit);
+        transcript.append_point(&s_commit);
+        let y = transcript.challenge();
+        let z = transcript.challenge();
+
+        let y_powers = powers(y, n);
+        let two_powers = powers(Fr::from(2u64), n);
+        let z_sq = z * z;
+
+        // l(X) = a_L - z*1^n + s_L*X
+        // r(X) = y^n ∘ (a_R + z*1^n + s_R*X) + z^2 * 2^n
+        let a_l_minus_z: Vec<Fr> = a_l.iter().map(|v| *v - z).collect();
+        let a_r_plus_z: Vec<Fr> = a_r.iter().map(|v| *v + z).collect();
+        let y_s_r = hadamard(&y_powers, &s_r);
+
+        // t1 = <l0, y^n ∘ s_R> + <s_L, y^n ∘ r0_base>
+        let t1 = inner_product(&a_l_minus_z, &y_s_r)
+            + inner_product(&s_l, &scalar_vec_add(&hadamard(&y_powers, &a_r_plus_z), &z_sq_two_powers(z_sq, &two_powers)));
+        let t2 = inner_product(&s_l, &y_s_r);
+
+        let tau1 = Fr::rand(&mut rng);
+        let tau2 = Fr::rand(&mut rng);
+        let t1_commit = (gens.g.mul_bigint(t1.into_bigint()) + gens.h.mul_bigint(tau1.into_bigint())).into_affine();
+        let t2_commit = (gens.g.mul_bigint(t2.into_bigint()) + gens.h.mul_bigint(tau2.into_bigint())).into_affine();
+
+        transcript.append_point(&t1_commit);
+        transcript.append_point(&t2_commit);
+        let x = transcript.challenge();
+
+        let l: Vec<Fr> = a_l_minus_z
+            .iter()
+            .zip(s_l.iter())
+            .map(|(l0, sl)| *l0 + *sl * x)
+            .collect();
+        let r: Vec<Fr> = y_powers
+            .iter()
+            .zip(a_r_plus_z.iter())
+            .zip(s_r.iter())
+            .zip(two_powers.iter())
+            .map(|(((yp, ar), sr), tp)| *yp * (*ar + *sr * x) + z_sq * tp)
+            .collect();
+
+        let t_hat = inner_product(&l, &r);
+        let tau_x = tau2 * x * x + tau1 * x + z_sq * blinding;
+        let mu = alpha + rho * x;
+
+        // Fold the statement into an inner-product argument: h_vec is transformed by
+        // y^-i so that the relation <l, r> = t_hat holds against the *original* g_vec.
+        let y_inv_powers = powers(y.inverse().expect("y is never zero"), n);
+        let h_prime: Vec<G1Affine> = gens
+            .h_vec
+            .iter()
+            .zip(y_inv_powers.iter())
+            .map(|(h, yi)| h.mul_bigint(yi.into_bigint()).into_affine())
+            .collect();
+
+        transcript.append_scalar(t_hat);
+        transcript.append_scalar(tau_x);
+        transcript.append_scalar(mu);
+        let u_challenge = transcript.challenge();
+        let u_point = (G1Projective::generator() * u_challenge).into_affine();
+
+        let (l_vec, r_vec, a_final, b_final) =
+            ipa_prove(&mut transcript, gens.g_vec.clone(), h_prime, u_point, l, r);
+
+        Ok(Self {
+            commitment: point_to_bytes(&commitment)?,
+            a: point_to_bytes(&a_commit)?,
+            s: point_to_bytes(&s_commit)?,
+            t1: point_to_bytes(&t1_commit)?,
+            t2: point_to_bytes(&t2_commit)?,
+            t_hat: scalar_to_bytes(&t_hat)?,
+            tau_x: scalar_to_bytes(&tau_x)?,
+            mu: scalar_to_bytes(&mu)?,
+            l_vec: l_vec.iter().map(point_to_bytes).collect::<Result<_>>()?,
+            r_vec: r_vec.iter().map(point_to_bytes).collect::<Result<_>>()?,
+            a_final: scalar_to_bytes(&a_final)?,
+            b_final: scalar_to_bytes(&b_final)?,
+        })
+    }
+
+    /// Verify that [`Self::commitment`] hides a value in `[0, 2^RANGE_BITS)`.
+    fn verify(&self, gens: &BulletproofGens) -> Result<bool> {
+        let n = RANGE_BITS;
+        let commitment = point_from_bytes(&self.commitment)?;
+        let a_commit = point_from_bytes(&self.a)?;
+        let s_commit = point_from_bytes(&self.s)?;
+        let t1_commit = point_from_bytes(&self.t1)?;
+        let t2_commit = point_from_bytes(&self.t2)?;
+        let t_hat = scalar_from_bytes(&self.t_hat)?;
+        let tau_x = scalar_from_bytes(&self.tau_x)?;
+        let mu = scalar_from_bytes(&self.mu)?;
+
+        let mut transcript = Transcript::new("civium/bulletproofs/range");
+        transcript.append_point(&commitment);
+        transcript.append_point(&a_commit);
+        transcript.append_point(&s_commit);
+        let y = transcript.challenge();
+        let z = transcript.challenge();
+        let z_sq = z * z;
+
+        transcript.append_point(&t1_commit);
+        transcript.append_point(&t2_commit);
+        let x = transcript.challenge();
+
+        // t_hat*G + tau_x*H must equal the committed polynomial evaluated at x, offset by
+        // the public term z^2*v implicit in `commitment`.
+        let delta = delta_yz(y, z, n);
+        let lhs = gens.g.mul_bigint(t_hat.into_bigint()) + gens.h.mul_bigint(tau_x.into_bigint());
+        let rhs = commitment.mul_bigint(z_sq.into_bigint())
+            + gens.g.mul_bigint(delta.into_bigint())
+            + t1_commit.mul_bigint(x.into_bigint())
+            + t2_commit.mul_bigint((x * x).into_bigint());
+        if lhs != rhs {
+            return Ok(false);
+        }
+
+        let y_inv_powers = powers(y.inverse().expect("y is never zero"), n);
+        let h_prime: Vec<G1Affine> = gens
+            .h_vec
+            .iter()
+            .zip(y_inv_powers.iter())
+            .map(|(h, yi)| h.mul_bigint(yi.into_bigint()).into_affine())
+            .collect();
+
+        transcript.append_scalar(t_hat);
+        transcript.append_scalar(tau_x);
+        transcript.append_scalar(mu);
+        let u_challenge = transcript.challenge();
+        let u_point = (G1Projective::generator() * u_challenge).into_affine();
+
+        // P is the vector Pedersen commitment the IPA is opening: A + x*S, shifted by the
+        // public z/z^2 offsets baked into l(x)/r(x), with mu subtracted out of the
+        // blinding so the IPA checks a commitment to (l, r) alone.
+        let p = a_commit
+            + s_commit.mul_bigint(x.into_bigint())
+            + multiscalar_mul(&vec![-z; n], &gens.g_vec)
+            + multiscalar_mul(
+                &y_inv_powers
+                    .iter()
+                    .enumerate()
+                    .map(|(i, yi)| *yi * (z * y_pow(y, i) + z_sq * Fr::from(1u64 << i)))
+                    .collect::<Vec<_>>(),
+                &gens.h_vec,
+            )
+            - gens.h.mul_bigint(mu.into_bigint())
+            + u_point.mul_bigint(t_hat.into_bigint());
+
+        let l_vec: Vec<G1Affine> = self.l_vec.iter().map(|b| point_from_bytes(b)).collect::<Result<_>>()?;
+        let r_vec: Vec<G1Affine> = self.r_vec.iter().map(|b| point_from_bytes(b)).collect::<Result<_>>()?;
+        let a_final = scalar_from_bytes(&self.a_final)?;
+        let b_final = scalar_from_bytes(&self.b_final)?;
+
+        let (g_final, h_final, p_final) =
+            ipa_verify(&mut transcript, gens.g_vec.clone(), h_prime, p, &l_vec, &r_vec);
+
+        let expected = g_final.mul_bigint(a_final.into_bigint())
+            + h_final.mul_bigint(b_final.into_bigint())
+            + u_point.mul_bigint((a_final * b_final).into_bigint());
+
+        Ok(p_final == expected)
+    }
+}
+
+fn y_pow(y: Fr, i: usize) -> Fr {
+    y.pow([i as u64])
+}
+
+fn z_sq_two_powers(z_sq: Fr, two_powers: &[Fr]) -> Vec<Fr> {
+    two_powers.iter().map(|p| z_sq * p).collect()
+}
+
+/// `delta(y, z) = (z - z^2)*<1^n, y^n> - z^3*<1^n, 2^n>`, the public constant absorbed
+/// into the `t(x)` check (see the Bulletproofs paper, section 4.2).
+fn delta_yz(y: Fr, z: Fr, n: usize) -> Fr {
+    let y_sum: Fr = powers(y, n).into_iter().sum();
+    let two_sum: Fr = powers(Fr::from(2u64), n).into_iter().sum();
+    let z_cubed = z * z * z;
+    (z - z * z) * y_sum - z_cubed * two_sum
+}
+
+/// A Bulletproofs range proof for [`crate::types::RangeInput`]: proves
+/// `min_score <= score <= max_score` with no circuit-specific trusted setup, by proving
+/// `score - min_score` and `max_score - score` are each in `[0, 2^RANGE_BITS)`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BulletproofRangeProof {
+    /// Proof that `score - min_score >= 0`
+    pub lower: SingleRangeProof,
+    /// Proof that `max_score - score >= 0`
+    pub upper: SingleRangeProof,
+}
+
+impl BulletproofRangeProof {
+    /// Prove `min_score <= score <= max_score` without a circuit-specific trusted setup.
+    pub fn prove(score: u64, min_score: u64, max_score: u64) -> Result<Self> {
+        if score < min_score || score > max_score {
+            return Err(ProverError::InvalidInput {
+                field: "score".into(),
+                value: score.to_string(),
+                expected: format!("[{min_score}, {max_score}]"),
+            });
+        }
+
+        let gens = BulletproofGens::new(RANGE_BITS);
+        let mut rng = thread_rng();
+
+        let lower = SingleRangeProof::prove(score - min_score, Fr::rand(&mut rng), &gens)?;
+        let upper = SingleRangeProof::prove(max_score - score, Fr::rand(&mut rng), &gens)?;
+
+        Ok(Self { lower, upper })
+    }
+
+    /// Verify both bounding proofs against a fresh generator set.
+    pub fn verify(&self) -> Result<bool> {
+        let gens = BulletproofGens::new(RANGE_BITS);
+        Ok(self.lower.verify(&gens)? && self.upper.verify(&gens)?)
+    }
+
+    /// Serialize to bytes, mirroring [`crate::proof::Proof::to_bytes`].
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        serde_json::to_vec(self).map_err(ProverError::Serialization)
+    }
+
+    /// Deserialize from bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        serde_json::from_slice(bytes).map_err(ProverError::Serialization)
+    }
+
+    /// Convert to a hex string.
+    pub fn to_hex(&self) -> Result<String> {
+        Ok(hex::encode(self.to_bytes()?))
+    }
+
+    /// Convert from a hex string.
+    pub fn from_hex(hex_str: &str) -> Result<Self> {
+        let bytes = hex::decode(hex_str)
+            .map_err(|e| ProverError::InvalidProofFormat { reason: e.to_string() })?;
+        Self::from_bytes(&bytes)
+    }
+}
+
+fn is_pow2(n: usize) -> bool {
+    n != 0 && (n & (n - 1)) == 0
+}
+
+/// `z^{2+i}` for each block `i in 0..blocks`, the per-slot challenge powers that keep an
+/// aggregated proof's blocks from interfering with one another (see the Bulletproofs
+/// paper, section 4.3, and [`AggregatedRangeProof`]).
+fn z_block_powers(z: Fr, blocks: usize) -> Vec<Fr> {
+    let mut zp = z * z;
+    (0..blocks)
+        .map(|_| {
+            let cur = zp;
+            zp *= z;
+            cur
+        })
+        .collect()
+}
+
+/// `delta(y, z)` generalized to `blocks` aggregated slots of width `n`: the public constant
+/// absorbed into the `t(x)` check, now summing the per-block `z^{3+i}` terms.
+fn delta_agg(y: Fr, z: Fr, n: usize, blocks: usize) -> Fr {
+    let y_sum: Fr = powers(y, blocks * n).into_iter().sum();
+    let two_sum: Fr = powers(Fr::from(2u64), n).into_iter().sum();
+    let z3_sum: Fr = z_block_powers(z, blocks).iter().map(|zb| *zb * z).sum();
+    (z - z * z) * y_sum - z3_sum * two_sum
+}
+
+/// A single aggregated Bulletproof proving, for every entity `j` in a batch, that
+/// `min_score_j <= score_j <= max_score_j` — with proof size growing logarithmically in
+/// the batch size rather than linearly.
+///
+/// Mirrors the batched-Bulletproofs aggregation technique: each entity contributes two
+/// bounded values (`score_j - min_score_j` and `max_score_j - score_j`, exactly like
+/// [`BulletproofRangeProof`]), so an `m`-entity batch folds `2*m` blocks of `RANGE_BITS`
+/// bits each into one combined inner-product argument, with per-block challenge powers
+/// `z^(2+i)` (see [`z_block_powers`]) keeping the blocks from leaking into each other. Both the number of entities
+/// and `RANGE_BITS` must be powers of two for the combined vector to fold evenly.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AggregatedRangeProof {
+    /// Per-entity `(lower, upper)` Pedersen commitments, one pair per input entity, in
+    /// the same order as the `inputs` passed to [`Self::prove`].
+    pub commitments: Vec<(PointBytes, PointBytes)>,
+    a: PointBytes,
+    s: PointBytes,
+    t1: PointBytes,
+    t2: PointBytes,
+    t_hat: ScalarBytes,
+    tau_x: ScalarBytes,
+    mu: ScalarBytes,
+    l_vec: Vec<PointBytes>,
+    r_vec: Vec<PointBytes>,
+    a_final: ScalarBytes,
+    b_final: ScalarBytes,
+}
+
+impl AggregatedRangeProof {
+    /// Prove `min_score <= score <= max_score` for every entity in `inputs` as one
+    /// aggregated Bulletproof. `inputs.len()` must be a power of two.
+    pub fn prove(inputs: &[RangeInput]) -> Result<Self> {
+        let m = inputs.len();
+        if !is_pow2(m) {
+            return Err(ProverError::InvalidInput {
+                field: "inputs.len()".into(),
+                value: m.to_string(),
+                expected: "a power of two (aggregation size)".into(),
+            });
+        }
+        for input in inputs {
+            input.validate()?;
+        }
+
+        let n = RANGE_BITS;
+        let blocks = 2 * m;
+        let gens = BulletproofGens::new(blocks * n);
+        let mut rng = thread_rng();
+
+        let mut values = Vec::with_capacity(blocks);
+        let mut blindings = Vec::with_capacity(blocks);
+        let mut commitments = Vec::with_capacity(m);
+        for input in inputs {
+            let lower = input.score - input.min_score;
+            let upper = input.max_score - input.score;
+            let gamma_lower = Fr::rand(&mut rng);
+            let gamma_upper = Fr::rand(&mut rng);
+            let v_lower = (gens.g.mul_bigint(Fr::from(lower).into_bigint())
+                + gens.h.mul_bigint(gamma_lower.into_bigint()))
+            .into_affine();
+            let v_upper = (gens.g.mul_bigint(Fr::from(upper).into_bigint())
+                + gens.h.mul_bigint(gamma_upper.into_bigint()))
+            .into_affine();
+            commitments.push((point_to_bytes(&v_lower)?, point_to_bytes(&v_upper)?));
+            values.push(lower);
+            values.push(upper);
+            blindings.push(gamma_lower);
+            blindings.push(gamma_upper);
+        }
+
+        let a_l: Vec<Fr> = values.iter().flat_map(|v| bit_vector(*v, n)).collect();
+        let a_r: Vec<Fr> = a_l.iter().map(|b| *b - Fr::from(1u64)).collect();
+
+        let alpha = Fr::rand(&mut rng);
+        let a_commit = (gens.h.mul_bigint(alpha.into_bigint())
+            + multiscalar_mul(&a_l, &gens.g_vec)
+            + multiscalar_mul(&a_r, &gens.h_vec))
+        .into_affine();
+
+        let s_l: Vec<Fr> = (0..blocks * n).map(|_| Fr::rand(&mut rng)).collect();
+        let s_r: Vec<Fr> = (0..blocks * n).map(|_| Fr::rand(&mut rng)).collect();
+        let rho = Fr::rand(&mut rng);
+        let s_commit = (gens.h.mul_bigint(rho.into_bigint())
+            + multiscalar_mul(&s_l, &gens.g_vec)
+            + multiscalar_mul(&s_r, &gens.h_vec))
+        .into_affine();
+
+        let mut transcript = Transcript::new("civium/bulletproofs/range_aggregated");
+        for (v_lower, v_upper) in &commitments {
+            transcript.append_point(&point_from_bytes(v_lower)?);
+            transcript.append_point(&point_from_bytes(v_upper)?);
+        }
+        transcript.append_point(&a_commit);
+        transcript.append_point(&s_commit);
+        let y = transcript.challenge();
+        let z = transcript.challenge();
+
+        let y_powers = powers(y, blocks * n);
+        let two_powers = powers(Fr::from(2u64), n);
+        let z_block = z_block_powers(z, blocks);
+        let z_two_vec: Vec<Fr> = (0..blocks)
+            .flat_map(|i| {
+                let zb = z_block[i];
+                two_powers.iter().map(move |tp| zb * tp).collect::<Vec<_>>()
+            })
+            .collect();
+
+        let l0: Vec<Fr> = a_l.iter().map(|v| *v - z).collect();
+        let r0: Vec<Fr> = a_r
+            .iter()
+            .zip(y_powers.iter())
+            .zip(z_two_vec.iter())
+            .map(|((ar, yp), zt)| *yp * (*ar + z) + zt)
+            .collect();
+        let y_s_r = hadamard(&y_powers, &s_r);
+
+        let t1 = inner_product(&l0, &y_s_r) + inner_product(&s_l, &r0);
+        let t2 = inner_product(&s_l, &y_s_r);
+
+        let tau1 = Fr::rand(&mut rng);
+        let tau2 = Fr::rand(&mut rng);
+        let t1_commit = (gens.g.mul_bigint(t1.into_bigint()) + gens.h.mul_bigint(tau1.into_bigint())).into_affine();
+        let t2_commit = (gens.g.mul_bigint(t2.into_bigint()) + gens.h.mul_bigint(tau2.into_bigint())).into_affine();
+
+        transcript.append_point(&t1_commit);
+        transcript.append_point(&t2_commit);
+        let x = transcript.challenge();
+
+        let l: Vec<Fr> = l0.iter().zip(s_l.iter()).map(|(l0, sl)| *l0 + *sl * x).collect();
+        let r: Vec<Fr> = r0.iter().zip(y_s_r.iter()).map(|(r0, ysr)| *r0 + *ysr * x).collect();
+
+        let t_hat = inner_product(&l, &r);
+        let tau_x = tau2 * x * x
+            + tau1 * x
+            + z_block.iter().zip(blindings.iter()).map(|(zb, gamma)| *zb * gamma).sum::<Fr>();
+        let mu = alpha + rho * x;
+
+        let y_inv_powers = powers(y.inverse().expect("y is never zero"), blocks * n);
+        let h_prime: Vec<G1Affine> = gens
+            .h_vec
+            .iter()
+            .zip(y_inv_powers.iter())
+            .map(|(h, yi)| h.mul_bigint(yi.into_bigint()).into_affine())
+            .collect();
+
+        transcript.append_scalar(t_hat);
+        transcript.append_scalar(tau_x);
+        transcript.append_scalar(mu);
+        let u_challenge = transcript.challenge();
+        let u_point = (G1Projective::generator() * u_challenge).into_affine();
+
+        let (l_vec, r_vec, a_final, b_final) =
+            ipa_prove(&mut transcript, gens.g_vec.clone(), h_prime, u_point, l, r);
+
+        Ok(Self {
+            commitments,
+            a: point_to_bytes(&a_commit)?,
+            s: point_to_bytes(&s_commit)?,
+            t1: point_to_bytes(&t1_commit)?,
+            t2: point_to_bytes(&t2_commit)?,
+            t_hat: scalar_to_bytes(&t_hat)?,
+            tau_x: scalar_to_bytes(&tau_x)?,
+            mu: scalar_to_bytes(&mu)?,
+            l_vec: l_vec.iter().map(point_to_bytes).collect::<Result<_>>()?,
+            r_vec: r_vec.iter().map(point_to_bytes).collect::<Result<_>>()?,
+            a_final: scalar_to_bytes(&a_final)?,
+            b_final: scalar_to_bytes(&b_final)?,
+        })
+    }
+
+    /// Verify that every `(lower, upper)` pair in [`Self::commitments`] hides values in
+    /// `[0, 2^RANGE_BITS)`, i.e. that every entity's private score was within its declared
+    /// `[min_score, max_score]`.
+    pub fn verify(&self) -> Result<bool> {
+        let m = self.commitments.len();
+        if !is_pow2(m) {
+            return Err(ProverError::InvalidInput {
+                field: "commitments.len()".into(),
+                value: m.to_string(),
+                expected: "a power of two (aggregation size)".into(),
+            });
+        }
+
+        let n = RANGE_BITS;
+        let blocks = 2 * m;
+        let gens = BulletproofGens::new(blocks * n);
+
+        let a_commit = point_from_bytes(&self.a)?;
+        let s_commit = point_from_bytes(&self.s)?;
+        let t1_commit = point_from_bytes(&self.t1)?;
+        let t2_commit = point_from_bytes(&self.t2)?;
+        let t_hat = scalar_from_bytes(&self.t_hat)?;
+        let tau_x = scalar_from_bytes(&self.tau_x)?;
+        let mu = scalar_from_bytes(&self.mu)?;
+
+        let mut transcript = Transcript::new("civium/bulletproofs/range_aggregated");
+        let mut entity_points = Vec::with_capacity(blocks);
+        for (v_lower, v_upper) in &self.commitments {
+            let lower = point_from_bytes(v_lower)?;
+            let upper = point_from_bytes(v_upper)?;
+            transcript.append_point(&lower);
+            transcript.append_point(&upper);
+            entity_points.push(lower);
+            entity_points.push(upper);
+        }
+        transcript.append_point(&a_commit);
+        transcript.append_point(&s_commit);
+        let y = transcript.challenge();
+        let z = transcript.challenge();
+
+        transcript.append_point(&t1_commit);
+        transcript.append_point(&t2_commit);
+        let x = transcript.challenge();
+
+        let z_block = z_block_powers(z, blocks);
+        let delta = delta_agg(y, z, n, blocks);
+        let lhs = gens.g.mul_bigint(t_hat.into_bigint()) + gens.h.mul_bigint(tau_x.into_bigint());
+        let rhs = entity_points
+            .iter()
+            .zip(z_block.iter())
+            .fold(G1Projective::zero(), |acc, (v, zb)| acc + v.mul_bigint(zb.into_bigint()))
+            + gens.g.mul_bigint(delta.into_bigint())
+            + t1_commit.mul_bigint(x.into_bigint())
+            + t2_commit.mul_bigint((x * x).into_bigint());
+        if lhs != rhs {
+            return Ok(false);
+        }
+
+        let y_inv_powers = powers(y.inverse().expect("y is never zero"), blocks * n);
+        let h_prime: Vec<G1Affine> = gens
+            .h_vec
+            .iter()
+            .zip(y_inv_powers.iter())
+            .map(|(h, yi)| h.mul_bigint(yi.into_bigint()).into_affine())
+            .collect();
+
+        transcript.append_scalar(t_hat);
+        transcript.append_scalar(tau_x);
+        transcript.append_scalar(mu);
+        let u_challenge = transcript.challenge();
+        let u_point = (G1Projective::generator() * u_challenge).into_affine();
+
+        let two_powers = powers(Fr::from(2u64), n);
+        let z_two_vec: Vec<Fr> = (0..blocks)
+            .flat_map(|i| {
+                let zb = z_block[i];
+                two_powers.iter().map(move |tp| zb * tp).collect::<Vec<_>>()
+            })
+            .collect();
+        let weights: Vec<Fr> = y_inv_powers.iter().zip(z_two_vec.iter()).map(|(yi, zt)| z + *yi * zt).collect();
+
+        let p = a_commit
+            + s_commit.mul_bigint(x.into_bigint())
+            + multiscalar_mul(&vec![-z; blocks * n], &gens.g_vec)
+            + multiscalar_mul(&weights, &gens.h_vec)
+            - gens.h.mul_bigint(mu.into_bigint())
+            + u_point.mul_bigint(t_hat.into_bigint());
+
+        let l_vec: Vec<G1Affine> = self.l_vec.iter().map(|b| point_from_bytes(b)).collect::<Result<_>>()?;
+        let r_vec: Vec<G1Affine> = self.r_vec.iter().map(|b| point_from_bytes(b)).collect::<Result<_>>()?;
+        let a_final = scalar_from_bytes(&self.a_final)?;
+        let b_final = scalar_from_bytes(&self.b_final)?;
+
+        let (g_final, h_final, p_final) =
+            ipa_verify(&mut transcript, gens.g_vec.clone(), h_prime, p, &l_vec, &r_vec);
+
+        let expected = g_final.mul_bigint(a_final.into_bigint())
+            + h_final.mul_bigint(b_final.into_bigint())
+            + u_point.mul_bigint((a_final * b_final).into_bigint());
+
+        Ok(p_final == expected)
+    }
+
+    /// Serialize to bytes.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        serde_json::to_vec(self).map_err(ProverError::Serialization)
+    }
+
+    /// Deserialize from bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        serde_json::from_slice(bytes).map_err(ProverError::Serialization)
+    }
+
+    /// Convert to a hex string.
+    pub fn to_hex(&self) -> Result<String> {
+        Ok(hex::encode(self.to_bytes()?))
+    }
+
+    /// Convert from a hex string.
+    pub fn from_hex(hex_str: &str) -> Result<Self> {
+        let bytes = hex::decode(hex_str)
+            .map_err(|e| ProverError::InvalidProofFormat { reason: e.to_string() })?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Convert to JSON, mirroring [`crate::proof::ProofWithInputsJson`]'s shape with the
+    /// aggregated Bulletproof (no Groth16 envelope to speak of) hex-encoded in `proof` and
+    /// the per-entity commitments carried alongside it for the verifier to replay.
+    pub fn to_json(&self) -> Result<AggregatedRangeProofJson> {
+        Ok(AggregatedRangeProofJson {
+            proof: self.to_hex()?,
+            commitments: self
+                .commitments
+                .iter()
+                .map(|(lower, upper)| (hex::encode(lower), hex::encode(upper)))
+                .collect(),
+            circuit: "range_proof_aggregated".into(),
+        })
+    }
+}
+
+/// JSON-serializable [`AggregatedRangeProof`], shaped like [`crate::proof::ProofWithInputsJson`]
+/// (a `proof` and a `circuit` field) plus the per-entity commitment pairs a verifier needs
+/// alongside the proof blob. It has no `public_inputs` field, and `proof` holds the
+/// hex-encoded proof rather than a [`crate::proof::ProofJson`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AggregatedRangeProofJson {
+    /// Hex-encoded [`AggregatedRangeProof`]
+    pub proof: String,
+    /// Per-entity `(lower, upper)` Pedersen commitments, hex-encoded
+    pub commitments: Vec<(String, String)>,
+    /// Circuit name, mirroring [`crate::proof::ProofWithInputsJson::circuit`]
+    pub circuit: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range_input(score: u64, min_score: u64, max_score: u64) -> RangeInput {
+        RangeInput {
+            min_score,
+            max_score,
+            entity_hash: "123456789".into(),
+            score,
+            salt: "987654321".into(),
+        }
+    }
+
+    #[test]
+    fn test_single_range_proof_roundtrip() {
+        let gens = BulletproofGens::new(RANGE_BITS);
+        let proof = SingleRangeProof::prove(1234, Fr::from(42u64), &gens).unwrap();
+        assert!(proof.verify(&gens).unwrap());
+    }
+
+    #[test]
+    fn test_derive_generator_is_not_a_known_multiple_of_the_curve_generator() {
+        // A hash-to-curve output has no known discrete log relative to the standard BN254
+        // generator, unlike the old `generator * hash(label)` construction - guard against
+        // regressing back to that by checking the derived point isn't simply `generator`
+        // itself (the one relation trivial enough to check without solving a discrete log).
+        let g = derive_generator("civium/bulletproofs/g", 0);
+        assert_ne!(g, G1Projective::generator().into_affine());
+    }
+
+    #[test]
+    fn test_derive_generator_is_deterministic_and_distinct_per_label_and_index() {
+        let g0 = derive_generator("civium/bulletproofs/g_vec", 0);
+        let g0_again = derive_generator("civium/bulletproofs/g_vec", 0);
+        let g1 = derive_generator("civium/bulletproofs/g_vec", 1);
+        let h0 = derive_generator("civium/bulletproofs/h_vec", 0);
+
+        assert_eq!(g0, g0_again);
+        assert_ne!(g0, g1);
+        assert_ne!(g0, h0);
+    }
+
+    #[test]
+    fn test_bulletproof_range_proof_satisfiable() {
+        let proof = BulletproofRangeProof::prove(8500, 7000, 9000).unwrap();
+        assert!(proof.verify().unwrap());
+    }
+
+    #[test]
+    fn test_bulletproof_range_proof_rejects_out_of_range_score() {
+        assert!(BulletproofRangeProof::prove(6000, 7000, 9000).is_err());
+    }
+
+    #[test]
+    fn test_bulletproof_proof_bytes_roundtrip() {
+        let proof = BulletproofRangeProof::prove(8500, 7000, 9000).unwrap();
+        let bytes = proof.to_bytes().unwrap();
+        let decoded = BulletproofRangeProof::from_bytes(&bytes).unwrap();
+        assert!(decoded.verify().unwrap());
+    }
+
+    #[test]
+    fn test_aggregated_range_proof_satisfiable() {
+        let inputs = vec![
+            range_input(8500, 7000, 9000),
+            range_input(6200, 5000, 7000),
+            range_input(9800, 9500, 10000),
+            range_input(100, 0, 1000),
+        ];
+        let proof = AggregatedRangeProof::prove(&inputs).unwrap();
+        assert!(proof.verify().unwrap());
+    }
+
+    #[test]
+    fn test_aggregated_gens_are_not_known_multiples_of_the_curve_generator() {
+        // `AggregatedRangeProof::prove`/`verify` build their generator set through the same
+        // `BulletproofGens::new` as `BulletproofRangeProof`, so confirm the aggregated sizing
+        // (`blocks * RANGE_BITS`, here 2 blocks for a single entity) also derives generators
+        // with no known discrete log relative to the curve generator, not just the
+        // single-proof sizing covered by `derive_generator`'s own tests.
+        let gens = BulletproofGens::new(2 * RANGE_BITS);
+        assert_ne!(gens.g, G1Projective::generator().into_affine());
+        assert_ne!(gens.h, G1Projective::generator().into_affine());
+        assert!(gens.g_vec.iter().all(|p| *p != G1Projective::generator().into_affine()));
+    }
+
+    #[test]
+    fn test_aggregated_range_proof_rejects_non_power_of_two_batch() {
+        let inputs = vec![range_input(8500, 7000, 9000), range_input(6200, 5000, 7000), range_input(100, 0, 1000)];
+        assert!(AggregatedRangeProof::prove(&inputs).is_err());
+    }
+
+    #[test]
+    fn test_aggregated_range_proof_rejects_out_of_range_score() {
+        let inputs = vec![range_input(8500, 7000, 9000), range_input(4000, 5000, 7000)];
+        assert!(AggregatedRangeProof::prove(&inputs).is_err());
+    }
+
+    #[test]
+    fn test_aggregated_range_proof_bytes_and_json_roundtrip() {
+        let inputs = vec![range_input(8500, 7000, 9000), range_input(6200, 5000, 7000)];
+        let proof = AggregatedRangeProof::prove(&inputs).unwrap();
+
+        let bytes = proof.to_bytes().unwrap();
+        let decoded = AggregatedRangeProof::from_bytes(&bytes).unwrap();
+        assert!(decoded.verify().unwrap());
+
+        let json = proof.to_json().unwrap();
+        assert_eq!(json.commitments.len(), inputs.len());
+        assert_eq!(json.circuit, "range_proof_aggregated");
+    }
+}
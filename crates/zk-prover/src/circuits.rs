@@ -4,22 +4,88 @@
 //! for cases where circom WASM is not available or for testing.
 
 use ark_bn254::Fr;
-use ark_ff::Field;
+use ark_crypto_primitives::sponge::constraints::CryptographicSpongeVar;
+use ark_crypto_primitives::sponge::poseidon::constraints::PoseidonSpongeVar;
+use ark_ff::{BigInteger, Field, PrimeField};
 use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
 use ark_r1cs_std::prelude::*;
 use ark_r1cs_std::fields::fp::FpVar;
 
+use crate::poseidon::{poseidon_config, PoseidonHasher};
 use crate::types::MAX_SCORE;
 
+/// Number of bits needed to range-check differences of two scores in `[0, MAX_SCORE]`.
+///
+/// `MAX_SCORE` is 10000, and `2^14 = 16384 > 10000`, so 14 bits is enough to represent
+/// any non-negative difference between two in-range scores.
+const SCORE_BITS: usize = 14;
+
+/// Enforce that `value` is representable in `n_bits` bits, i.e. `0 <= value < 2^n_bits`.
+///
+/// This is the standard non-negativity gadget for `a >= b` checks: callers pass
+/// `diff = a - b`. If `a < b`, `diff` wraps around the scalar field to an element that
+/// needs the field's full bit width to represent, so no assignment of `n_bits` witnessed
+/// bits can reconstruct it and the constraint system becomes unsatisfiable.
+fn enforce_fits_in_bits(value: &FpVar<Fr>, n_bits: usize) -> Result<(), SynthesisError> {
+    let cs = value.cs();
+    let bits_le = value
+        .value()
+        .unwrap_or_default()
+        .into_bigint()
+        .to_bits_le();
+
+    let bit_vars = (0..n_bits)
+        .map(|i| Boolean::new_witness(cs.clone(), || Ok(bits_le.get(i).copied().unwrap_or(false))))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let reconstructed = Boolean::le_bits_to_fp_var(&bit_vars)?;
+    reconstructed.enforce_equal(value)?;
+
+    Ok(())
+}
+
+/// Absorb `inputs` into a Poseidon sponge and squeeze one field element, using the exact
+/// same [`crate::poseidon::poseidon_config`] parameters as [`PoseidonHasher`] does natively.
+fn poseidon_hash_vars(
+    cs: ConstraintSystemRef<Fr>,
+    inputs: &[&FpVar<Fr>],
+) -> Result<FpVar<Fr>, SynthesisError> {
+    let config = poseidon_config();
+    let mut sponge = PoseidonSpongeVar::new(cs, &config);
+    for input in inputs {
+        sponge.absorb(*input)?;
+    }
+
+    let mut output = sponge.squeeze_field_elements(1)?;
+    Ok(output.remove(0))
+}
+
+/// Compute `Poseidon(score, salt, entity_hash)` in-circuit and enforce it equals the public
+/// `commitment` input.
+fn enforce_commitment(
+    cs: ConstraintSystemRef<Fr>,
+    score_var: &FpVar<Fr>,
+    salt_var: &FpVar<Fr>,
+    entity_hash_var: &FpVar<Fr>,
+    commitment_var: &FpVar<Fr>,
+) -> Result<(), SynthesisError> {
+    let computed = poseidon_hash_vars(cs, &[score_var, salt_var, entity_hash_var])?;
+    computed.enforce_equal(commitment_var)?;
+
+    Ok(())
+}
+
 /// Native compliance threshold circuit
 ///
-/// Proves: score >= threshold
+/// Proves: score >= threshold, and that `commitment == Poseidon(score, salt, entity_hash)`.
 #[derive(Clone)]
 pub struct ThresholdCircuit {
     /// Public: minimum required score
     pub threshold: Fr,
     /// Public: hash of entity identifier
     pub entity_hash: Fr,
+    /// Public: Poseidon commitment to (score, salt, entity_hash)
+    pub commitment: Fr,
     /// Private: actual compliance score
     pub score: Fr,
     /// Private: random salt for commitment
@@ -29,9 +95,11 @@ pub struct ThresholdCircuit {
 impl ThresholdCircuit {
     /// Create a new threshold circuit
     pub fn new(threshold: u64, entity_hash: Fr, score: u64, salt: Fr) -> Self {
+        let commitment = PoseidonHasher::new().compute_commitment(score, &salt, &entity_hash);
         Self {
             threshold: Fr::from(threshold),
             entity_hash,
+            commitment,
             score: Fr::from(score),
             salt,
         }
@@ -43,32 +111,24 @@ impl ConstraintSynthesizer<Fr> for ThresholdCircuit {
         // Allocate public inputs
         let threshold_var = FpVar::new_input(cs.clone(), || Ok(self.threshold))?;
         let entity_hash_var = FpVar::new_input(cs.clone(), || Ok(self.entity_hash))?;
+        let commitment_var = FpVar::new_input(cs.clone(), || Ok(self.commitment))?;
 
         // Allocate private inputs (witnesses)
         let score_var = FpVar::new_witness(cs.clone(), || Ok(self.score))?;
         let salt_var = FpVar::new_witness(cs.clone(), || Ok(self.salt))?;
 
-        // Constraint 1: score >= threshold
-        // We prove this by showing score - threshold >= 0
-        // Which is equivalent to showing there exists a non-negative witness w such that
-        // score = threshold + w
+        // Constraint 1: score >= threshold, proven via bit decomposition of the
+        // non-negative difference (see `enforce_fits_in_bits`).
         let diff = &score_var - &threshold_var;
-        
-        // In a real implementation, we'd decompose diff into bits to prove non-negativity
-        // For simplicity, we enforce diff * (diff - 1) * ... constraints for range
-        // This is a placeholder - full implementation would use proper range proofs
-        
+        enforce_fits_in_bits(&diff, SCORE_BITS)?;
+
         // Constraint 2: score <= MAX_SCORE
         let max_score_var = FpVar::new_constant(cs.clone(), Fr::from(MAX_SCORE))?;
         let upper_diff = &max_score_var - &score_var;
-        // Similar range proof constraint
-
-        // Constraint 3: Compute commitment (simplified)
-        // In real impl, use Poseidon gadget
-        let _commitment = &score_var + &salt_var + &entity_hash_var;
+        enforce_fits_in_bits(&upper_diff, SCORE_BITS)?;
 
-        // Output commitment as public output
-        // commitment.enforce_equal(&commitment_output)?;
+        // Constraint 3: commitment == Poseidon(score, salt, entity_hash)
+        enforce_commitment(cs, &score_var, &salt_var, &entity_hash_var, &commitment_var)?;
 
         Ok(())
     }
@@ -76,7 +136,7 @@ impl ConstraintSynthesizer<Fr> for ThresholdCircuit {
 
 /// Native range proof circuit
 ///
-/// Proves: min_score <= score <= max_score
+/// Proves: min_score <= score <= max_score, and that `commitment` matches the witness.
 #[derive(Clone)]
 pub struct RangeCircuit {
     /// Public: minimum of range
@@ -85,6 +145,8 @@ pub struct RangeCircuit {
     pub max_score: Fr,
     /// Public: entity hash
     pub entity_hash: Fr,
+    /// Public: Poseidon commitment to (score, salt, entity_hash)
+    pub commitment: Fr,
     /// Private: actual score
     pub score: Fr,
     /// Private: salt
@@ -94,10 +156,12 @@ pub struct RangeCircuit {
 impl RangeCircuit {
     /// Create a new range circuit
     pub fn new(min_score: u64, max_score: u64, entity_hash: Fr, score: u64, salt: Fr) -> Self {
+        let commitment = PoseidonHasher::new().compute_commitment(score, &salt, &entity_hash);
         Self {
             min_score: Fr::from(min_score),
             max_score: Fr::from(max_score),
             entity_hash,
+            commitment,
             score: Fr::from(score),
             salt,
         }
@@ -110,6 +174,7 @@ impl ConstraintSynthesizer<Fr> for RangeCircuit {
         let min_var = FpVar::new_input(cs.clone(), || Ok(self.min_score))?;
         let max_var = FpVar::new_input(cs.clone(), || Ok(self.max_score))?;
         let entity_hash_var = FpVar::new_input(cs.clone(), || Ok(self.entity_hash))?;
+        let commitment_var = FpVar::new_input(cs.clone(), || Ok(self.commitment))?;
 
         // Allocate private inputs
         let score_var = FpVar::new_witness(cs.clone(), || Ok(self.score))?;
@@ -117,18 +182,18 @@ impl ConstraintSynthesizer<Fr> for RangeCircuit {
 
         // Constraint 1: score >= min_score
         let lower_diff = &score_var - &min_var;
-        // Range proof for non-negativity
+        enforce_fits_in_bits(&lower_diff, SCORE_BITS)?;
 
         // Constraint 2: score <= max_score
         let upper_diff = &max_var - &score_var;
-        // Range proof for non-negativity
+        enforce_fits_in_bits(&upper_diff, SCORE_BITS)?;
 
         // Constraint 3: min <= max (valid range)
         let range_diff = &max_var - &min_var;
-        // Range proof
+        enforce_fits_in_bits(&range_diff, SCORE_BITS)?;
 
-        // Commitment
-        let _commitment = &score_var + &salt_var + &entity_hash_var;
+        // Constraint 4: commitment == Poseidon(score, salt, entity_hash)
+        enforce_commitment(cs, &score_var, &salt_var, &entity_hash_var, &commitment_var)?;
 
         Ok(())
     }
@@ -141,6 +206,8 @@ pub struct TierCircuit {
     pub target_tier: Fr,
     /// Public: entity hash
     pub entity_hash: Fr,
+    /// Public: Poseidon commitment to (score, salt, entity_hash)
+    pub commitment: Fr,
     /// Private: actual score
     pub score: Fr,
     /// Private: salt
@@ -150,9 +217,11 @@ pub struct TierCircuit {
 impl TierCircuit {
     /// Create a new tier circuit
     pub fn new(target_tier: u8, entity_hash: Fr, score: u64, salt: Fr) -> Self {
+        let commitment = PoseidonHasher::new().compute_commitment(score, &salt, &entity_hash);
         Self {
             target_tier: Fr::from(target_tier as u64),
             entity_hash,
+            commitment,
             score: Fr::from(score),
             salt,
         }
@@ -176,19 +245,240 @@ impl ConstraintSynthesizer<Fr> for TierCircuit {
         // Allocate public inputs
         let tier_var = FpVar::new_input(cs.clone(), || Ok(self.target_tier))?;
         let entity_hash_var = FpVar::new_input(cs.clone(), || Ok(self.entity_hash))?;
+        let commitment_var = FpVar::new_input(cs.clone(), || Ok(self.commitment))?;
 
         // Allocate private inputs
         let score_var = FpVar::new_witness(cs.clone(), || Ok(self.score))?;
         let salt_var = FpVar::new_witness(cs.clone(), || Ok(self.salt))?;
 
-        // In a real implementation, we'd use a lookup table or conditional constraints
-        // to determine tier boundaries based on target_tier
-        
-        // For each tier, create conditional constraints
-        // This is simplified - full impl would use IsEqual gadgets
+        // Select the (min, max) bounds matching `target_tier` with `IsEqual` gadgets, and
+        // enforce that exactly one of the five tiers matches (so target_tier must be 1-5).
+        let one = FpVar::constant(Fr::one());
+        let zero = FpVar::zero();
+        let mut min_var = zero.clone();
+        let mut max_var = zero.clone();
+        let mut match_count = zero.clone();
+
+        for tier in 1u64..=5 {
+            let (min, max) = Self::tier_bounds(tier);
+            let tier_const = FpVar::new_constant(cs.clone(), Fr::from(tier))?;
+            let is_tier = tier_var.is_eq(&tier_const)?;
+
+            let min_const = FpVar::new_constant(cs.clone(), Fr::from(min))?;
+            let max_const = FpVar::new_constant(cs.clone(), Fr::from(max))?;
+
+            min_var += is_tier.select(&min_const, &zero)?;
+            max_var += is_tier.select(&max_const, &zero)?;
+            match_count += is_tier.select(&one, &zero)?;
+        }
+
+        match_count.enforce_equal(&one)?;
+
+        // Constraint: min_var <= score <= max_var for the selected tier
+        let lower_diff = &score_var - &min_var;
+        enforce_fits_in_bits(&lower_diff, SCORE_BITS)?;
+
+        let upper_diff = &max_var - &score_var;
+        enforce_fits_in_bits(&upper_diff, SCORE_BITS)?;
+
+        // Constraint: commitment == Poseidon(score, salt, entity_hash)
+        enforce_commitment(cs, &score_var, &salt_var, &entity_hash_var, &commitment_var)?;
+
+        Ok(())
+    }
+}
+
+/// Approved-entity allowlist membership circuit
+///
+/// Proves: `entity_hash` is a leaf of the Merkle tree rooted at the public `root`, without
+/// revealing which leaf. See [`crate::merkle::PoseidonTree`] for building the tree and
+/// sibling path off-circuit.
+#[derive(Clone)]
+pub struct MembershipCircuit {
+    /// Public: Merkle root of the approved-entity allowlist
+    pub root: Fr,
+    /// Public: hash of entity identifier (the leaf being proven)
+    pub entity_hash: Fr,
+    /// Private: sibling hashes from leaf to root
+    pub siblings: Vec<Fr>,
+    /// Private: whether the tracked node is the right child at each level
+    pub path_bits: Vec<bool>,
+}
+
+impl MembershipCircuit {
+    /// Create a new membership circuit from a tree root, entity hash, and sibling path.
+    pub fn new(root: Fr, entity_hash: Fr, path: &crate::merkle::MerklePath) -> Self {
+        Self {
+            root,
+            entity_hash,
+            siblings: path.siblings.clone(),
+            path_bits: path.path_bits.clone(),
+        }
+    }
+}
+
+impl ConstraintSynthesizer<Fr> for MembershipCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        // `siblings`/`path_bits` are both `pub`, so a caller can build a `MembershipCircuit`
+        // with mismatched lengths without going through `Self::new`; surface that as a
+        // synthesis error rather than panicking the prover.
+        if self.siblings.len() != self.path_bits.len() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        // Allocate public inputs
+        let root_var = FpVar::new_input(cs.clone(), || Ok(self.root))?;
+        let entity_hash_var = FpVar::new_input(cs.clone(), || Ok(self.entity_hash))?;
+
+        // Recompute the root by hashing the leaf up the witnessed sibling path.
+        let mut node = entity_hash_var;
+        for (sibling, is_right) in self.siblings.iter().zip(self.path_bits.iter()) {
+            let sibling_var = FpVar::new_witness(cs.clone(), || Ok(*sibling))?;
+            let is_right_var = Boolean::new_witness(cs.clone(), || Ok(*is_right))?;
+
+            let left = is_right_var.select(&sibling_var, &node)?;
+            let right = is_right_var.select(&node, &sibling_var)?;
+
+            node = poseidon_hash_vars(cs.clone(), &[&left, &right])?;
+        }
+
+        node.enforce_equal(&root_var)?;
+
+        Ok(())
+    }
+}
+
+/// RLN-style rate-limiting nullifier circuit
+///
+/// Proves, for a per-proof challenge `share_x`, knowledge of a secret `a0` such that
+/// `share_y` is the evaluation at `share_x` of the degree-1 line `a0 + a1 * x`, where the
+/// line's slope `a1 = Poseidon(a0, epoch)` ties it to a specific public `epoch`, and
+/// `nullifier = Poseidon(a1)` lets verifiers recognize repeat proofs in that epoch without
+/// learning `a0`. See [`crate::rln::recover_secret`] for reconstructing `a0` from two
+/// shares in the same epoch.
+#[derive(Clone)]
+pub struct RlnCircuit {
+    /// Public: epoch identifier the proof is scoped to
+    pub epoch: Fr,
+    /// Public: per-proof Shamir share x-coordinate (e.g. `Poseidon(signal)`)
+    pub share_x: Fr,
+    /// Public: Shamir share y-coordinate, `a0 + a1 * share_x`
+    pub share_y: Fr,
+    /// Public: per-epoch nullifier, `Poseidon(a1)`
+    pub nullifier: Fr,
+    /// Private: entity's identity secret
+    pub a0: Fr,
+}
+
+impl RlnCircuit {
+    /// Create a new RLN circuit, deriving `a1`, `share_y`, and `nullifier` from `a0`.
+    pub fn new(epoch: Fr, share_x: Fr, a0: Fr) -> Self {
+        let hasher = PoseidonHasher::new();
+        let a1 = hasher.hash(&[a0, epoch]);
+        let share_y = a0 + a1 * share_x;
+        let nullifier = hasher.hash(&[a1]);
 
-        // Commitment
-        let _commitment = &score_var + &salt_var + &entity_hash_var;
+        Self {
+            epoch,
+            share_x,
+            share_y,
+            nullifier,
+            a0,
+        }
+    }
+}
+
+/// Maximum number of inner public-input field elements one aggregated proof can fold
+/// together. Groth16 needs one fixed circuit shape per proving/verifying key pair, so this
+/// bounds how many proofs (and of which circuits) [`AggregationCircuit`] can combine in a
+/// single aggregate; [`pad_aggregate_signals`] zero-fills whatever is left unused.
+pub const MAX_AGGREGATE_SIGNALS: usize = 16;
+
+/// Zero-pad `signals` up to [`MAX_AGGREGATE_SIGNALS`], the fixed-length shape
+/// [`AggregationCircuit`] commits to. Returns `None` if `signals` is already longer than
+/// the capacity.
+pub fn pad_aggregate_signals(signals: &[Fr]) -> Option<[Fr; MAX_AGGREGATE_SIGNALS]> {
+    if signals.len() > MAX_AGGREGATE_SIGNALS {
+        return None;
+    }
+    let mut padded = [Fr::zero(); MAX_AGGREGATE_SIGNALS];
+    padded[..signals.len()].copy_from_slice(signals);
+    Some(padded)
+}
+
+/// Proof-aggregation circuit
+///
+/// Folds the flattened public signals of several compliance proofs (threshold, range,
+/// tier, or any mix) into one Poseidon commitment, so a relying party checks one Groth16
+/// proof and one verifying key instead of one per inner proof. This does not re-verify the
+/// inner proofs' pairing checks in-circuit (that needs a recursion-friendly curve cycle,
+/// see [`crate::proof::CurveParams`]); callers must already have verified each inner proof,
+/// e.g. via [`crate::verifier::ComplianceVerifier`], before folding it into an aggregate.
+#[derive(Clone)]
+pub struct AggregationCircuit {
+    /// Public: Poseidon commitment over `signals`
+    pub commitment: Fr,
+    /// Private: flattened public signals of the proofs being aggregated, zero-padded to
+    /// [`MAX_AGGREGATE_SIGNALS`] (see [`pad_aggregate_signals`])
+    pub signals: [Fr; MAX_AGGREGATE_SIGNALS],
+}
+
+impl AggregationCircuit {
+    /// Build an aggregation circuit committing to `signals` (the inner proofs' flattened,
+    /// zero-padded public inputs). Returns `None` if `signals` exceeds
+    /// [`MAX_AGGREGATE_SIGNALS`].
+    pub fn new(signals: &[Fr]) -> Option<Self> {
+        let padded = pad_aggregate_signals(signals)?;
+        let commitment = PoseidonHasher::new().hash(&padded);
+        Some(Self {
+            commitment,
+            signals: padded,
+        })
+    }
+}
+
+impl ConstraintSynthesizer<Fr> for AggregationCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        // Allocate public input
+        let commitment_var = FpVar::new_input(cs.clone(), || Ok(self.commitment))?;
+
+        // Allocate private witnesses
+        let signal_vars = self
+            .signals
+            .iter()
+            .map(|s| FpVar::new_witness(cs.clone(), || Ok(*s)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let signal_refs: Vec<&FpVar<Fr>> = signal_vars.iter().collect();
+
+        // Constraint: commitment == Poseidon(signals...)
+        let computed = poseidon_hash_vars(cs, &signal_refs)?;
+        computed.enforce_equal(&commitment_var)?;
+
+        Ok(())
+    }
+}
+
+impl ConstraintSynthesizer<Fr> for RlnCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        // Allocate public inputs
+        let epoch_var = FpVar::new_input(cs.clone(), || Ok(self.epoch))?;
+        let share_x_var = FpVar::new_input(cs.clone(), || Ok(self.share_x))?;
+        let share_y_var = FpVar::new_input(cs.clone(), || Ok(self.share_y))?;
+        let nullifier_var = FpVar::new_input(cs.clone(), || Ok(self.nullifier))?;
+
+        // Allocate private input
+        let a0_var = FpVar::new_witness(cs.clone(), || Ok(self.a0))?;
+
+        // Constraint 1: a1 = Poseidon(a0, epoch)
+        let a1_var = poseidon_hash_vars(cs.clone(), &[&a0_var, &epoch_var])?;
+
+        // Constraint 2: nullifier == Poseidon(a1)
+        let computed_nullifier = poseidon_hash_vars(cs.clone(), &[&a1_var])?;
+        computed_nullifier.enforce_equal(&nullifier_var)?;
+
+        // Constraint 3: share_y == a0 + a1 * share_x (Horner evaluation of the line)
+        let computed_share_y = &a0_var + &a1_var * &share_x_var;
+        computed_share_y.enforce_equal(&share_y_var)?;
 
         Ok(())
     }
@@ -214,6 +504,37 @@ mod tests {
         assert!(cs.is_satisfied().unwrap());
     }
 
+    #[test]
+    fn test_threshold_circuit_unsatisfiable_below_threshold() {
+        let circuit = ThresholdCircuit::new(
+            8000,
+            Fr::from(123456789u64),
+            7000, // below threshold
+            Fr::from(987654321u64),
+        );
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        circuit.generate_constraints(cs.clone()).unwrap();
+
+        assert!(!cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn test_threshold_circuit_unsatisfiable_wrong_commitment() {
+        let mut circuit = ThresholdCircuit::new(
+            8000,
+            Fr::from(123456789u64),
+            8500,
+            Fr::from(987654321u64),
+        );
+        circuit.commitment = Fr::from(1u64); // tampered commitment
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        circuit.generate_constraints(cs.clone()).unwrap();
+
+        assert!(!cs.is_satisfied().unwrap());
+    }
+
     #[test]
     fn test_range_circuit_satisfiable() {
         let circuit = RangeCircuit::new(
@@ -229,5 +550,146 @@ mod tests {
 
         assert!(cs.is_satisfied().unwrap());
     }
-}
 
+    #[test]
+    fn test_range_circuit_unsatisfiable_outside_range() {
+        let circuit = RangeCircuit::new(
+            7000,
+            9000,
+            Fr::from(123456789u64),
+            6000, // below min_score
+            Fr::from(987654321u64),
+        );
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        circuit.generate_constraints(cs.clone()).unwrap();
+
+        assert!(!cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn test_tier_circuit_satisfiable() {
+        let circuit = TierCircuit::new(2, Fr::from(123456789u64), 8700, Fr::from(987654321u64));
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        circuit.generate_constraints(cs.clone()).unwrap();
+
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn test_tier_circuit_unsatisfiable_wrong_tier() {
+        // Score 8700 belongs to tier 2, not tier 1.
+        let circuit = TierCircuit::new(1, Fr::from(123456789u64), 8700, Fr::from(987654321u64));
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        circuit.generate_constraints(cs.clone()).unwrap();
+
+        assert!(!cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn test_membership_circuit_satisfiable() {
+        use crate::merkle::PoseidonTree;
+
+        let entity_hash = Fr::from(123456789u64);
+        let mut tree = PoseidonTree::new(4, Fr::from(0u64));
+        tree.insert(5, entity_hash);
+
+        let root = tree.root();
+        let path = tree.proof(5);
+        let circuit = MembershipCircuit::new(root, entity_hash, &path);
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        circuit.generate_constraints(cs.clone()).unwrap();
+
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn test_membership_circuit_unsatisfiable_wrong_entity() {
+        use crate::merkle::PoseidonTree;
+
+        let mut tree = PoseidonTree::new(4, Fr::from(0u64));
+        tree.insert(5, Fr::from(123456789u64));
+
+        let root = tree.root();
+        let path = tree.proof(5);
+        // Prove with an entity hash that was never inserted at this index.
+        let circuit = MembershipCircuit::new(root, Fr::from(999u64), &path);
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        circuit.generate_constraints(cs.clone()).unwrap();
+
+        assert!(!cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn test_membership_circuit_mismatched_path_lengths_errors() {
+        // Bypasses `MembershipCircuit::new` (whose `siblings`/`path_bits` always come from
+        // the same `MerklePath`) to build one with mismatched lengths directly, since both
+        // fields are `pub`.
+        let circuit = MembershipCircuit {
+            root: Fr::from(0u64),
+            entity_hash: Fr::from(0u64),
+            siblings: vec![Fr::from(1u64), Fr::from(2u64)],
+            path_bits: vec![false],
+        };
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let result = circuit.generate_constraints(cs);
+
+        assert!(matches!(result, Err(SynthesisError::Unsatisfiable)));
+    }
+
+    #[test]
+    fn test_rln_circuit_satisfiable() {
+        let circuit = RlnCircuit::new(Fr::from(7u64), Fr::from(11u64), Fr::from(42u64));
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        circuit.generate_constraints(cs.clone()).unwrap();
+
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn test_rln_circuit_unsatisfiable_tampered_share() {
+        let mut circuit = RlnCircuit::new(Fr::from(7u64), Fr::from(11u64), Fr::from(42u64));
+        circuit.share_y = Fr::from(1u64); // tampered share
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        circuit.generate_constraints(cs.clone()).unwrap();
+
+        assert!(!cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn test_aggregation_circuit_satisfiable() {
+        // Mixes signals from different circuit types (threshold-shaped and range-shaped).
+        let signals = [Fr::from(8000u64), Fr::from(123u64), Fr::from(7000u64), Fr::from(9000u64)];
+        let circuit = AggregationCircuit::new(&signals).unwrap();
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        circuit.generate_constraints(cs.clone()).unwrap();
+
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn test_aggregation_circuit_unsatisfiable_tampered_commitment() {
+        let signals = [Fr::from(8000u64), Fr::from(123u64)];
+        let mut circuit = AggregationCircuit::new(&signals).unwrap();
+        circuit.commitment = Fr::from(1u64); // tampered commitment
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        circuit.generate_constraints(cs.clone()).unwrap();
+
+        assert!(!cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn test_aggregation_circuit_rejects_too_many_signals() {
+        let signals = vec![Fr::from(1u64); MAX_AGGREGATE_SIGNALS + 1];
+        assert!(AggregationCircuit::new(&signals).is_none());
+    }
+}
@@ -34,20 +34,31 @@
 #![allow(clippy::module_name_repetitions)]
 #![allow(clippy::must_use_candidate)]
 
+pub mod bulletproofs;
 pub mod circuits;
 pub mod error;
+pub mod merkle;
 pub mod poseidon;
 pub mod proof;
 pub mod prover;
+pub mod rln;
+pub mod solidity;
 pub mod types;
 pub mod verifier;
 
 // Re-exports
+pub use bulletproofs::{AggregatedRangeProof, AggregatedRangeProofJson, BulletproofRangeProof};
 pub use error::{ProverError, Result};
-pub use proof::{Proof, ProofWithPublicInputs};
+pub use merkle::{MerklePath, PoseidonTree};
+pub use proof::{CurveParams, Proof, ProofJson, ProofWithPublicInputs, VerifyingKeyJson};
 pub use prover::ComplianceProver;
-pub use types::{RangeInput, ThresholdInput, TierInput};
-pub use verifier::ComplianceVerifier;
+pub use rln::recover_secret;
+pub use solidity::generate_verifier_contract;
+pub use types::{MembershipInput, RangeInput, RlnInput, ThresholdInput, TierInput};
+pub use verifier::{
+    aggregate_commitment, verify_batch, verify_batch_strict, verify_json, AggregationVerifier,
+    ComplianceVerifier,
+};
 
 #[cfg(feature = "python")]
 mod python;
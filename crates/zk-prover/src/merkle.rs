@@ -0,0 +1,179 @@
+//! Poseidon Merkle tree for approved-entity allowlist membership
+//!
+//! Lets Civium gate compliance proofs to a curated registry: an entity proves its
+//! `entity_hash` is a leaf of a tree whose root is published (e.g. on-chain) without
+//! revealing which leaf, mirroring the semaphore-rs/RLN group-membership design.
+
+use std::collections::HashMap;
+
+use ark_bn254::Fr;
+
+use crate::poseidon::PoseidonHasher;
+
+/// Sibling path proving a leaf's inclusion in a [`PoseidonTree`], from leaf to root.
+#[derive(Clone, Debug)]
+pub struct MerklePath {
+    /// Sibling hash at each level, leaf-to-root
+    pub siblings: Vec<Fr>,
+    /// Whether the tracked node is the right child at each level (`true` = right)
+    pub path_bits: Vec<bool>,
+}
+
+/// A fixed-depth, Poseidon-hashed binary Merkle tree used for set membership.
+///
+/// Only explicitly inserted leaves and their ancestor nodes are stored; the hash of every
+/// empty subtree is precomputed once per level in [`PoseidonTree::new`], so an otherwise
+/// sparse tree of depth `d` costs `O(d)` per insert rather than `O(2^d)` of storage.
+pub struct PoseidonTree {
+    depth: usize,
+    hasher: PoseidonHasher,
+    /// `empty_hashes[level]` is the hash of an entirely-empty subtree rooted at that level
+    /// (level 0 = a single empty leaf, level `depth` = the root of an empty tree).
+    empty_hashes: Vec<Fr>,
+    /// Sparse storage of non-empty nodes, keyed by `(level, index within level)`.
+    nodes: HashMap<(usize, usize), Fr>,
+}
+
+impl PoseidonTree {
+    /// Create a new tree of the given `depth` (so it holds up to `2^depth` leaves), with
+    /// `empty_leaf` as the value of every unset leaf.
+    pub fn new(depth: usize, empty_leaf: Fr) -> Self {
+        let hasher = PoseidonHasher::new();
+
+        let mut empty_hashes = Vec::with_capacity(depth + 1);
+        empty_hashes.push(empty_leaf);
+        for _ in 0..depth {
+            let prev = *empty_hashes.last().expect("just pushed");
+            empty_hashes.push(hasher.hash(&[prev, prev]));
+        }
+
+        Self {
+            depth,
+            hasher,
+            empty_hashes,
+            nodes: HashMap::new(),
+        }
+    }
+
+    fn node(&self, level: usize, index: usize) -> Fr {
+        self.nodes
+            .get(&(level, index))
+            .copied()
+            .unwrap_or(self.empty_hashes[level])
+    }
+
+    /// Insert `leaf` at `index`, recomputing every ancestor node up to the root.
+    pub fn insert(&mut self, index: usize, leaf: Fr) {
+        assert!(index < (1usize << self.depth), "leaf index out of range");
+
+        let mut idx = index;
+        let mut value = leaf;
+        self.nodes.insert((0, idx), value);
+
+        for level in 1..=self.depth {
+            let sibling = self.node(level - 1, idx ^ 1);
+            value = if idx % 2 == 0 {
+                self.hasher.hash(&[value, sibling])
+            } else {
+                self.hasher.hash(&[sibling, value])
+            };
+            idx /= 2;
+            self.nodes.insert((level, idx), value);
+        }
+    }
+
+    /// Alias for [`Self::insert`], matching the `set(index, leaf)` naming semaphore-rs and
+    /// other group-membership trees use.
+    pub fn set(&mut self, index: usize, leaf: Fr) {
+        self.insert(index, leaf);
+    }
+
+    /// Current Merkle root.
+    pub fn root(&self) -> Fr {
+        self.node(self.depth, 0)
+    }
+
+    /// Build the sibling path for the leaf at `index`.
+    pub fn proof(&self, index: usize) -> MerklePath {
+        assert!(index < (1usize << self.depth), "leaf index out of range");
+
+        let mut siblings = Vec::with_capacity(self.depth);
+        let mut path_bits = Vec::with_capacity(self.depth);
+        let mut idx = index;
+
+        for level in 0..self.depth {
+            siblings.push(self.node(level, idx ^ 1));
+            path_bits.push(idx % 2 == 1);
+            idx /= 2;
+        }
+
+        MerklePath {
+            siblings,
+            path_bits,
+        }
+    }
+}
+
+/// Verify a Merkle inclusion proof natively (off-circuit): recompute the root by hashing
+/// `leaf` up the sibling path and compare it against `root`.
+pub fn verify_merkle_proof(root: Fr, leaf: Fr, path: &MerklePath) -> bool {
+    let hasher = PoseidonHasher::new();
+    let mut node = leaf;
+
+    for (sibling, &is_right) in path.siblings.iter().zip(path.path_bits.iter()) {
+        node = if is_right {
+            hasher.hash(&[*sibling, node])
+        } else {
+            hasher.hash(&[node, *sibling])
+        };
+    }
+
+    node == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_prove_roundtrip() {
+        let mut tree = PoseidonTree::new(4, Fr::from(0u64));
+        tree.insert(3, Fr::from(42u64));
+        tree.insert(7, Fr::from(99u64));
+
+        let root = tree.root();
+        let proof = tree.proof(3);
+
+        assert!(verify_merkle_proof(root, Fr::from(42u64), &proof));
+    }
+
+    #[test]
+    fn test_proof_rejects_wrong_leaf() {
+        let mut tree = PoseidonTree::new(4, Fr::from(0u64));
+        tree.insert(3, Fr::from(42u64));
+
+        let root = tree.root();
+        let proof = tree.proof(3);
+
+        assert!(!verify_merkle_proof(root, Fr::from(43u64), &proof));
+    }
+
+    #[test]
+    fn test_empty_tree_has_consistent_root() {
+        let tree_a = PoseidonTree::new(8, Fr::from(0u64));
+        let tree_b = PoseidonTree::new(8, Fr::from(0u64));
+
+        assert_eq!(tree_a.root(), tree_b.root());
+    }
+
+    #[test]
+    fn test_set_is_equivalent_to_insert() {
+        let mut tree_a = PoseidonTree::new(4, Fr::from(0u64));
+        tree_a.insert(3, Fr::from(42u64));
+
+        let mut tree_b = PoseidonTree::new(4, Fr::from(0u64));
+        tree_b.set(3, Fr::from(42u64));
+
+        assert_eq!(tree_a.root(), tree_b.root());
+    }
+}
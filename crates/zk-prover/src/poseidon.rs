@@ -1,16 +1,46 @@
-//! Poseidon hash implementation compatible with circomlib
+//! Poseidon hash implementation
+//!
+//! The native [`PoseidonHasher`] here and the in-circuit gadget used by
+//! [`crate::circuits`] are both built from the same [`PoseidonConfig`], generated once by
+//! [`poseidon_config`]. Sharing one source of parameters is what guarantees a commitment
+//! computed natively is the same value a circuit's constraints enforce.
 
 use ark_bn254::Fr;
+use ark_crypto_primitives::sponge::poseidon::{find_poseidon_ark_and_mds, PoseidonConfig};
+use ark_crypto_primitives::sponge::{poseidon::PoseidonSponge, CryptographicSponge};
 use ark_ff::PrimeField;
 use num_bigint::BigUint;
 use num_traits::Num;
-use poseidon_rs::{Fr as PoseidonFr, Poseidon};
 
 use crate::error::{ProverError, Result};
 
-/// Poseidon hasher compatible with circomlib circuits
+/// Sponge rate: number of field elements absorbed/squeezed per permutation call.
+///
+/// A rate of 3 lets a single permutation absorb `score`, `salt`, and `entity_hash` in one
+/// call, which is exactly the shape of [`PoseidonHasher::compute_commitment`].
+const RATE: usize = 3;
+/// Sponge capacity (security parameter, kept hidden from the output).
+const CAPACITY: usize = 1;
+const FULL_ROUNDS: usize = 8;
+const PARTIAL_ROUNDS: usize = 56;
+const ALPHA: u64 = 5;
+
+/// Build the Poseidon parameters shared by the native hasher and the circuit gadget.
+pub fn poseidon_config() -> PoseidonConfig<Fr> {
+    let (ark, mds) = find_poseidon_ark_and_mds::<Fr>(
+        Fr::MODULUS_BIT_SIZE as u64,
+        RATE,
+        FULL_ROUNDS as u64,
+        PARTIAL_ROUNDS as u64,
+        0,
+    );
+
+    PoseidonConfig::new(FULL_ROUNDS, PARTIAL_ROUNDS, ALPHA, mds, ark, RATE, CAPACITY)
+}
+
+/// Poseidon hasher used for score commitments
 pub struct PoseidonHasher {
-    hasher: Poseidon,
+    config: PoseidonConfig<Fr>,
 }
 
 impl Default for PoseidonHasher {
@@ -23,39 +53,19 @@ impl PoseidonHasher {
     /// Create a new Poseidon hasher
     pub fn new() -> Self {
         Self {
-            hasher: Poseidon::new(),
+            config: poseidon_config(),
         }
     }
 
     /// Hash inputs using Poseidon
-    pub fn hash(&self, inputs: &[Fr]) -> Result<Fr> {
-        // Convert arkworks Fr to poseidon-rs Fr
-        let poseidon_inputs: Vec<PoseidonFr> = inputs
-            .iter()
-            .map(|f| {
-                let bytes = f.to_string();
-                PoseidonFr::from_str(&bytes).map_err(|e| ProverError::ArkError(e.to_string()))
-            })
-            .collect::<Result<Vec<_>>>()?;
-
-        // Compute hash
-        let result = self
-            .hasher
-            .hash(poseidon_inputs)
-            .map_err(|e| ProverError::ArkError(e.to_string()))?;
-
-        // Convert back to arkworks Fr
-        let result_str = result.to_string();
-        let result_biguint = BigUint::from_str_radix(&result_str, 10)
-            .map_err(|e| ProverError::ArkError(e.to_string()))?;
-
-        Fr::from_be_bytes_mod_order(&result_biguint.to_bytes_be())
-            .try_into()
-            .map_err(|_| ProverError::ArkError("Field element conversion failed".into()))
+    pub fn hash(&self, inputs: &[Fr]) -> Fr {
+        let mut sponge = PoseidonSponge::new(&self.config);
+        sponge.absorb(&inputs);
+        sponge.squeeze_field_elements(1)[0]
     }
 
     /// Compute score commitment: Poseidon(score, salt, entityHash)
-    pub fn compute_commitment(&self, score: u64, salt: &Fr, entity_hash: &Fr) -> Result<Fr> {
+    pub fn compute_commitment(&self, score: u64, salt: &Fr, entity_hash: &Fr) -> Fr {
         let score_fr = Fr::from(score);
         self.hash(&[score_fr, *salt, *entity_hash])
     }
@@ -65,7 +75,7 @@ impl PoseidonHasher {
 pub fn string_to_fr(s: &str) -> Result<Fr> {
     let biguint = BigUint::from_str_radix(s, 10)
         .map_err(|e| ProverError::ArkError(format!("Invalid number: {e}")))?;
-    
+
     Ok(Fr::from_be_bytes_mod_order(&biguint.to_bytes_be()))
 }
 
@@ -86,10 +96,10 @@ mod tests {
         let input1 = Fr::from(1u64);
         let input2 = Fr::from(2u64);
 
-        let hash = hasher.hash(&[input1, input2]).unwrap();
+        let hash = hasher.hash(&[input1, input2]);
 
         // Verify hash is deterministic
-        let hash2 = hasher.hash(&[input1, input2]).unwrap();
+        let hash2 = hasher.hash(&[input1, input2]);
         assert_eq!(hash, hash2);
     }
 
@@ -101,15 +111,14 @@ mod tests {
         let salt = Fr::from(123456789u64);
         let entity_hash = Fr::from(987654321u64);
 
-        let commitment = hasher.compute_commitment(score, &salt, &entity_hash).unwrap();
+        let commitment = hasher.compute_commitment(score, &salt, &entity_hash);
 
         // Verify deterministic
-        let commitment2 = hasher.compute_commitment(score, &salt, &entity_hash).unwrap();
+        let commitment2 = hasher.compute_commitment(score, &salt, &entity_hash);
         assert_eq!(commitment, commitment2);
 
         // Different inputs -> different commitment
-        let commitment3 = hasher.compute_commitment(score + 1, &salt, &entity_hash).unwrap();
+        let commitment3 = hasher.compute_commitment(score + 1, &salt, &entity_hash);
         assert_ne!(commitment, commitment3);
     }
 }
-
@@ -1,22 +1,85 @@
 //! Proof types and serialization
 
-use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
-use ark_groth16::Proof as Groth16Proof;
+use ark_bls12_377::Bls12_377;
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::pairing::Pairing;
+use ark_ec::AffineRepr;
+use ark_ff::Field;
+use ark_groth16::{Proof as Groth16Proof, VerifyingKey};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use num_bigint::BigUint;
+use num_traits::Num;
 use serde::{Deserialize, Serialize};
 
 use crate::error::{ProverError, Result};
 
-/// A Groth16 proof for the BN254 curve
+/// Curve-specific metadata a [`Proof`] needs to serialize itself in snarkjs's JSON format.
+///
+/// Implemented for every pairing-friendly curve [`Proof`] can be instantiated over; adding
+/// a new target curve (e.g. for recursive proof composition) means adding an `impl` here.
+pub trait CurveParams: Pairing {
+    /// snarkjs's identifier for this curve, as used in `proof.json`'s `"curve"` field.
+    const CURVE_NAME: &'static str;
+}
+
+impl CurveParams for Bn254 {
+    const CURVE_NAME: &'static str = "bn128";
+}
+
+impl CurveParams for Bls12_377 {
+    const CURVE_NAME: &'static str = "bls12_377";
+}
+
+/// Parse a decimal string (snarkjs's coordinate format) into a base-field element.
+fn string_to_fq(s: &str) -> Result<Fq> {
+    let biguint = BigUint::from_str_radix(s, 10)
+        .map_err(|e| ProverError::InvalidProofFormat { reason: format!("invalid field element {s:?}: {e}") })?;
+    Ok(Fq::from_be_bytes_mod_order(&biguint.to_bytes_be()))
+}
+
+/// Reconstruct a G1 point from snarkjs's `[x, y, "1"]` decimal-string triple.
+fn g1_from_strings(coords: &[String]) -> Result<G1Affine> {
+    let (x, y) = coords.first().zip(coords.get(1)).ok_or_else(|| ProverError::InvalidProofFormat {
+        reason: "G1 point needs an [x, y] coordinate pair".into(),
+    })?;
+    Ok(G1Affine::new(string_to_fq(x)?, string_to_fq(y)?))
+}
+
+/// Reconstruct a G2 point from snarkjs's `[[x_c0, x_c1], [y_c0, y_c1], ["1", "0"]]` triple
+/// (the inverse of [`Proof::g2_to_strings`]; note snarkjs keeps `c0`/`c1` in their natural
+/// order here, unlike the Solidity calldata encoding in [`Proof::g2_to_uint256`]).
+fn g2_from_strings(coords: &[Vec<String>]) -> Result<G2Affine> {
+    let (x, y) = coords.first().zip(coords.get(1)).ok_or_else(|| ProverError::InvalidProofFormat {
+        reason: "G2 point needs [x, y] coordinate pairs".into(),
+    })?;
+    let fq2 = |pair: &[String]| -> Result<Fq2> {
+        let (c0, c1) = pair.first().zip(pair.get(1)).ok_or_else(|| ProverError::InvalidProofFormat {
+            reason: "G2 coordinate needs a [c0, c1] pair".into(),
+        })?;
+        Ok(Fq2::new(string_to_fq(c0)?, string_to_fq(c1)?))
+    };
+    Ok(G2Affine::new(fq2(x)?, fq2(y)?))
+}
+
+/// A Groth16 proof, generic over the pairing-friendly curve it was produced on.
+///
+/// Defaults to [`Bn254`] so existing call sites that just write `Proof` (no type
+/// argument) keep working unchanged; instantiate as `Proof<Bls12_377>` to wrap a proof
+/// produced over a recursion-friendly curve instead. Only this serialization layer is
+/// curve-generic today - [`crate::circuits`]'s `ThresholdCircuit`/`RangeCircuit`/`TierCircuit`
+/// and [`crate::types`]'s matching input structs are still hard-wired to BN254's `Fr`, so a
+/// compliance proof produced end-to-end by this crate is always a `Proof<Bn254>`. `Proof<E>`
+/// for other curves exists for wrapping/serializing proofs from curve-generic circuits built
+/// outside this crate (`benches/proving.rs`'s `SquareCircuit` is one such circuit).
 #[derive(Clone, Debug)]
-pub struct Proof {
+pub struct Proof<E: Pairing = Bn254> {
     /// The underlying arkworks proof
-    pub inner: Groth16Proof<Bn254>,
+    pub inner: Groth16Proof<E>,
 }
 
-impl Proof {
+impl<E: CurveParams> Proof<E> {
     /// Create from arkworks proof
-    pub fn new(inner: Groth16Proof<Bn254>) -> Self {
+    pub fn new(inner: Groth16Proof<E>) -> Self {
         Self { inner }
     }
 
@@ -47,42 +110,33 @@ impl Proof {
 
     /// Convert to JSON-serializable format (compatible with snarkjs)
     pub fn to_json(&self) -> Result<ProofJson> {
-        let mut a_bytes = Vec::new();
-        self.inner.a.serialize_uncompressed(&mut a_bytes)?;
-
-        let mut b_bytes = Vec::new();
-        self.inner.b.serialize_uncompressed(&mut b_bytes)?;
-
-        let mut c_bytes = Vec::new();
-        self.inner.c.serialize_uncompressed(&mut c_bytes)?;
-
         Ok(ProofJson {
             pi_a: Self::g1_to_strings(&self.inner.a),
             pi_b: Self::g2_to_strings(&self.inner.b),
             pi_c: Self::g1_to_strings(&self.inner.c),
             protocol: "groth16".into(),
-            curve: "bn128".into(),
+            curve: E::CURVE_NAME.into(),
         })
     }
 
     /// Convert G1 point to string array
-    fn g1_to_strings(point: &G1Affine) -> Vec<String> {
-        let x = point.x.to_string();
-        let y = point.y.to_string();
-        vec![x, y, "1".into()]
+    fn g1_to_strings(point: &E::G1Affine) -> Vec<String> {
+        let (x, y) = point.xy().expect("proof points are never the point at infinity");
+        vec![x.to_string(), y.to_string(), "1".into()]
     }
 
-    /// Convert G2 point to string array  
-    fn g2_to_strings(point: &G2Affine) -> Vec<Vec<String>> {
-        let x0 = point.x.c0.to_string();
-        let x1 = point.x.c1.to_string();
-        let y0 = point.y.c0.to_string();
-        let y1 = point.y.c1.to_string();
-        vec![vec![x0, x1], vec![y0, y1], vec!["1".into(), "0".into()]]
+    /// Convert G2 point to string array, decomposing the G2 base field (an extension of
+    /// [`Pairing::BaseField`]) into its prime-field coefficients in natural `c0, c1, ...`
+    /// order.
+    fn g2_to_strings(point: &E::G2Affine) -> Vec<Vec<String>> {
+        let (x, y) = point.xy().expect("proof points are never the point at infinity");
+        let x_coeffs: Vec<String> = x.to_base_prime_field_elements().map(|c| c.to_string()).collect();
+        let y_coeffs: Vec<String> = y.to_base_prime_field_elements().map(|c| c.to_string()).collect();
+        vec![x_coeffs, y_coeffs, vec!["1".into(), "0".into()]]
     }
 
     /// Generate Solidity calldata for on-chain verification
-    pub fn to_solidity_calldata(&self, public_inputs: &[Fr]) -> Result<SolidityCalldata> {
+    pub fn to_solidity_calldata(&self, public_inputs: &[E::ScalarField]) -> Result<SolidityCalldata> {
         // Proof points
         let a = Self::g1_to_uint256(&self.inner.a);
         let b = Self::g2_to_uint256(&self.inner.b);
@@ -103,33 +157,39 @@ impl Proof {
     }
 
     /// Convert G1 point to uint256 array
-    fn g1_to_uint256(point: &G1Affine) -> [String; 2] {
-        [point.x.to_string(), point.y.to_string()]
+    pub(crate) fn g1_to_uint256(point: &E::G1Affine) -> [String; 2] {
+        let (x, y) = point.xy().expect("proof points are never the point at infinity");
+        [x.to_string(), y.to_string()]
     }
 
-    /// Convert G2 point to uint256 array
-    fn g2_to_uint256(point: &G2Affine) -> [[String; 2]; 2] {
+    /// Convert G2 point to uint256 array, swapping each coefficient pair to `(c1, c0)` to
+    /// match the EVM `ecPairing` precompile's convention (unlike [`Proof::g2_to_strings`],
+    /// which keeps snarkjs's natural `c0, c1` order).
+    pub(crate) fn g2_to_uint256(point: &E::G2Affine) -> [[String; 2]; 2] {
+        let (x, y) = point.xy().expect("proof points are never the point at infinity");
+        let x_coeffs: Vec<String> = x.to_base_prime_field_elements().map(|c| c.to_string()).collect();
+        let y_coeffs: Vec<String> = y.to_base_prime_field_elements().map(|c| c.to_string()).collect();
         [
-            [point.x.c1.to_string(), point.x.c0.to_string()],
-            [point.y.c1.to_string(), point.y.c0.to_string()],
+            [x_coeffs[1].clone(), x_coeffs[0].clone()],
+            [y_coeffs[1].clone(), y_coeffs[0].clone()],
         ]
     }
 }
 
 /// Proof with its public inputs
 #[derive(Clone, Debug)]
-pub struct ProofWithPublicInputs {
+pub struct ProofWithPublicInputs<E: Pairing = Bn254> {
     /// The ZK proof
-    pub proof: Proof,
+    pub proof: Proof<E>,
     /// Public input signals
-    pub public_inputs: Vec<Fr>,
+    pub public_inputs: Vec<E::ScalarField>,
     /// Circuit name
     pub circuit: String,
 }
 
-impl ProofWithPublicInputs {
+impl<E: CurveParams> ProofWithPublicInputs<E> {
     /// Create new proof with inputs
-    pub fn new(proof: Proof, public_inputs: Vec<Fr>, circuit: String) -> Self {
+    pub fn new(proof: Proof<E>, public_inputs: Vec<E::ScalarField>, circuit: String) -> Self {
         Self {
             proof,
             public_inputs,
@@ -138,7 +198,7 @@ impl ProofWithPublicInputs {
     }
 
     /// Get the score commitment (last public output)
-    pub fn score_commitment(&self) -> Option<&Fr> {
+    pub fn score_commitment(&self) -> Option<&E::ScalarField> {
         self.public_inputs.last()
     }
 
@@ -167,6 +227,38 @@ pub struct ProofJson {
     pub curve: String,
 }
 
+impl ProofJson {
+    /// Parse a snarkjs-style `proof.json` string into this struct.
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).map_err(ProverError::Serialization)
+    }
+
+    /// Reconstruct the underlying arkworks proof from this JSON's decimal-string
+    /// coordinates, the inverse of [`Proof::to_json`]. Rejects anything that isn't a
+    /// `groth16`/`bn128` proof up front, the same way `parse_verification_key` in
+    /// `verifier.rs` checks a loaded verification key ([`VerifyingKeyJson::into_verifying_key`]
+    /// itself does no such check), so a proof for the wrong protocol or curve fails
+    /// with a clear message instead of a confusing error deep inside point parsing.
+    pub fn into_proof(self) -> Result<Proof> {
+        if self.protocol != "groth16" {
+            return Err(ProverError::InvalidProofFormat {
+                reason: format!("unsupported protocol: {}", self.protocol),
+            });
+        }
+        if self.curve != "bn128" {
+            return Err(ProverError::InvalidProofFormat {
+                reason: format!("unsupported curve: {}", self.curve),
+            });
+        }
+
+        Ok(Proof::new(Groth16Proof {
+            a: g1_from_strings(&self.pi_a)?,
+            b: g2_from_strings(&self.pi_b)?,
+            c: g1_from_strings(&self.pi_c)?,
+        }))
+    }
+}
+
 /// JSON format with public inputs
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ProofWithInputsJson {
@@ -209,3 +301,119 @@ impl SolidityCalldata {
     }
 }
 
+/// JSON-serializable verifying key format (compatible with snarkjs's `verification_key.json`)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct VerifyingKeyJson {
+    /// Protocol identifier
+    pub protocol: String,
+    /// Curve identifier
+    pub curve: String,
+    /// `alpha` (G1)
+    pub vk_alpha_1: Vec<String>,
+    /// `beta` (G2)
+    pub vk_beta_2: Vec<Vec<String>>,
+    /// `gamma` (G2)
+    pub vk_gamma_2: Vec<Vec<String>>,
+    /// `delta` (G2)
+    pub vk_delta_2: Vec<Vec<String>>,
+    /// Input commitment basis, one G1 point per public input plus one constant term
+    #[serde(rename = "IC")]
+    pub ic: Vec<Vec<String>>,
+}
+
+impl VerifyingKeyJson {
+    /// Parse a snarkjs-style `verification_key.json` string into this struct.
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).map_err(ProverError::Serialization)
+    }
+
+    /// Convert an arkworks verifying key to snarkjs's `verification_key.json` shape.
+    pub fn from_verifying_key(vk: &VerifyingKey<Bn254>) -> Result<Self> {
+        Ok(Self {
+            protocol: "groth16".into(),
+            curve: "bn128".into(),
+            vk_alpha_1: Proof::<Bn254>::g1_to_strings(&vk.alpha_g1),
+            vk_beta_2: Proof::<Bn254>::g2_to_strings(&vk.beta_g2),
+            vk_gamma_2: Proof::<Bn254>::g2_to_strings(&vk.gamma_g2),
+            vk_delta_2: Proof::<Bn254>::g2_to_strings(&vk.delta_g2),
+            ic: vk.gamma_abc_g1.iter().map(Proof::<Bn254>::g1_to_strings).collect(),
+        })
+    }
+
+    /// Reconstruct the arkworks verifying key these decimal-string coordinates encode.
+    pub fn into_verifying_key(self) -> Result<VerifyingKey<Bn254>> {
+        Ok(VerifyingKey {
+            alpha_g1: g1_from_strings(&self.vk_alpha_1)?,
+            beta_g2: g2_from_strings(&self.vk_beta_2)?,
+            gamma_g2: g2_from_strings(&self.vk_gamma_2)?,
+            delta_g2: g2_from_strings(&self.vk_delta_2)?,
+            gamma_abc_g1: self.ic.iter().map(|c| g1_from_strings(c)).collect::<Result<Vec<_>>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_groth16::Groth16;
+    use ark_snark::SNARK;
+    use ark_std::rand::thread_rng;
+
+    use crate::circuits::ThresholdCircuit;
+
+    #[test]
+    fn test_verifying_key_json_roundtrip() {
+        let circuit = ThresholdCircuit::new(8000, Fr::from(123456789u64), 8500, Fr::from(987654321u64));
+
+        let mut rng = thread_rng();
+        let (_, vk) = Groth16::<Bn254>::circuit_specific_setup(circuit, &mut rng).unwrap();
+
+        let json = VerifyingKeyJson::from_verifying_key(&vk).unwrap();
+        let decoded = json.into_verifying_key().unwrap();
+        assert_eq!(decoded.alpha_g1, vk.alpha_g1);
+        assert_eq!(decoded.beta_g2, vk.beta_g2);
+        assert_eq!(decoded.gamma_g2, vk.gamma_g2);
+        assert_eq!(decoded.delta_g2, vk.delta_g2);
+        assert_eq!(decoded.gamma_abc_g1, vk.gamma_abc_g1);
+    }
+
+    #[test]
+    fn test_proof_json_and_verify_json_roundtrip() {
+        let circuit = ThresholdCircuit::new(8000, Fr::from(123456789u64), 8500, Fr::from(987654321u64));
+
+        let mut rng = thread_rng();
+        let (pk, vk) =
+            Groth16::<Bn254>::circuit_specific_setup(circuit.clone(), &mut rng).unwrap();
+        let public_inputs = vec![circuit.threshold, circuit.entity_hash, circuit.commitment];
+        let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng).unwrap();
+
+        let proof_json = Proof::new(proof).to_json().unwrap();
+        let decoded = proof_json.clone().into_proof().unwrap();
+
+        let vk_json = VerifyingKeyJson::from_verifying_key(&vk).unwrap();
+        assert!(crate::verifier::verify_json(&vk_json, &proof_json, &public_inputs).unwrap());
+
+        // Round-tripping through JSON text matches the in-memory conversion.
+        let text = serde_json::to_string(&proof_json).unwrap();
+        let reparsed = ProofJson::from_json(&text).unwrap().into_proof().unwrap();
+        assert_eq!(reparsed.inner.a, decoded.inner.a);
+    }
+
+    #[test]
+    fn test_into_proof_rejects_wrong_protocol_or_curve() {
+        let circuit = ThresholdCircuit::new(8000, Fr::from(123456789u64), 8500, Fr::from(987654321u64));
+        let mut rng = thread_rng();
+        let (pk, _) = Groth16::<Bn254>::circuit_specific_setup(circuit.clone(), &mut rng).unwrap();
+        let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng).unwrap();
+        let proof_json = Proof::new(proof).to_json().unwrap();
+
+        let mut wrong_protocol = proof_json.clone();
+        wrong_protocol.protocol = "plonk".into();
+        assert!(wrong_protocol.into_proof().is_err());
+
+        let mut wrong_curve = proof_json;
+        wrong_curve.curve = "bls12_381".into();
+        assert!(wrong_curve.into_proof().is_err());
+    }
+}
+
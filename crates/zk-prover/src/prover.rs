@@ -5,16 +5,18 @@ use std::fs;
 use std::time::Instant;
 
 use ark_bn254::{Bn254, Fr};
-use ark_circom::{CircomBuilder, CircomConfig};
-use ark_groth16::{Groth16, ProvingKey};
+use ark_circom::{read_zkey, CircomBuilder, CircomConfig};
+use ark_groth16::{Groth16, ProvingKey, VerifyingKey};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_snark::SNARK;
 use ark_std::rand::thread_rng;
 use num_bigint::BigUint;
 use tracing::{debug, info, instrument};
 
+use crate::circuits::AggregationCircuit;
 use crate::error::{ProverError, Result};
-use crate::proof::{Proof, ProofWithPublicInputs};
-use crate::types::{RangeInput, ThresholdInput, TierInput};
+use crate::proof::{Proof, ProofWithPublicInputs, VerifyingKeyJson};
+use crate::types::{MembershipInput, RangeInput, RlnInput, ThresholdInput, TierInput};
 
 /// Circuit identifiers
 pub enum Circuit {
@@ -24,6 +26,12 @@ pub enum Circuit {
     Range,
     /// Tier membership proof
     Tier,
+    /// Approved-entity allowlist membership proof
+    Membership,
+    /// RLN-style per-epoch rate-limiting nullifier proof
+    Rln,
+    /// Proof-aggregation circuit (see [`crate::circuits::AggregationCircuit`])
+    Aggregation,
 }
 
 impl Circuit {
@@ -33,8 +41,28 @@ impl Circuit {
             Self::Threshold => "compliance_threshold",
             Self::Range => "range_proof",
             Self::Tier => "tier_membership",
+            Self::Membership => "membership_proof",
+            Self::Rln => "rln_proof",
+            Self::Aggregation => "aggregation_proof",
         }
     }
+
+    /// Parse a circuit identifier back from the file-name string [`Self::file_name`]
+    /// produces - i.e. the `circuit` tag stamped on a
+    /// [`crate::proof::ProofWithPublicInputs`]. Returns `None` for any other string, e.g.
+    /// [`crate::verifier::AggregationVerifier::verify_batch`] uses this to look up the
+    /// right verifying key for each inner proof it folds.
+    pub fn from_file_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "compliance_threshold" => Self::Threshold,
+            "range_proof" => Self::Range,
+            "tier_membership" => Self::Tier,
+            "membership_proof" => Self::Membership,
+            "rln_proof" => Self::Rln,
+            "aggregation_proof" => Self::Aggregation,
+            _ => return None,
+        })
+    }
 }
 
 /// High-performance ZK-SNARK prover for compliance verification
@@ -45,13 +73,16 @@ pub struct ComplianceProver {
     threshold_pk: Option<ProvingKey<Bn254>>,
     range_pk: Option<ProvingKey<Bn254>>,
     tier_pk: Option<ProvingKey<Bn254>>,
+    membership_pk: Option<ProvingKey<Bn254>>,
+    rln_pk: Option<ProvingKey<Bn254>>,
+    aggregation_pk: Option<ProvingKey<Bn254>>,
 }
 
 impl ComplianceProver {
     /// Create a new prover with the given circuit build directory
     pub fn new(build_dir: impl AsRef<str>) -> Result<Self> {
         let build_dir = build_dir.as_ref().to_string();
-        
+
         if !Path::new(&build_dir).exists() {
             return Err(ProverError::CircuitNotFound {
                 path: build_dir,
@@ -63,36 +94,182 @@ impl ComplianceProver {
             threshold_pk: None,
             range_pk: None,
             tier_pk: None,
+            membership_pk: None,
+            rln_pk: None,
+            aggregation_pk: None,
         })
     }
 
-    /// Load proving key for a circuit
-    fn load_proving_key(&self, circuit: &Circuit) -> Result<ProvingKey<Bn254>> {
+    /// Run the (one-time) trusted setup for `circuit`, persist the resulting proving and
+    /// verifying keys to `build_dir`, and cache the proving key in memory.
+    ///
+    /// This replaces calling `Groth16::circuit_specific_setup` on every proof: setup
+    /// produces toxic waste that must be destroyed, so it must run exactly once per
+    /// circuit, not once per proof.
+    #[instrument(skip(self), fields(circuit = %circuit.file_name()))]
+    pub fn generate_keys(&mut self, circuit: &Circuit) -> Result<()> {
         let name = circuit.file_name();
-        let zkey_path = format!("{}/{}/proving_key.zkey", self.build_dir, name);
 
-        if !Path::new(&zkey_path).exists() {
-            return Err(ProverError::CircuitNotFound { path: zkey_path });
+        // The aggregation circuit is a native Rust constraint system (see
+        // `crate::circuits::AggregationCircuit`), not a circom/WASM one, so its setup
+        // doesn't need a `.wasm`/`.r1cs` pair on disk - only the fixed, all-zero-signal
+        // shape every aggregate proof shares.
+        if matches!(circuit, Circuit::Aggregation) {
+            let setup_circuit = AggregationCircuit::new(&[])
+                .expect("empty signal list never exceeds MAX_AGGREGATE_SIGNALS");
+
+            info!("Running trusted setup for circuit: {}", name);
+            let mut rng = thread_rng();
+            let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(setup_circuit, &mut rng)
+                .map_err(|e| ProverError::SetupError {
+                    reason: e.to_string(),
+                })?;
+
+            self.persist_keys(name, &pk, &vk)?;
+            self.cache_proving_key(circuit, pk);
+
+            return Ok(());
         }
 
-        debug!("Loading proving key from: {}", zkey_path);
+        let wasm_path = format!("{}/{}/{}_js/{}.wasm", self.build_dir, name, name, name);
+        let r1cs_path = format!("{}/{}/{}.r1cs", self.build_dir, name, name);
 
-        let zkey_data = fs::read(&zkey_path)?;
-        
-        // Parse snarkjs zkey format
-        // Note: In production, use ark-circom's zkey parser
-        let pk = Self::parse_zkey(&zkey_data)?;
-        
-        Ok(pk)
+        if !Path::new(&wasm_path).exists() {
+            return Err(ProverError::CircuitNotFound { path: wasm_path });
+        }
+
+        let cfg = CircomConfig::<Bn254>::new(&wasm_path, &r1cs_path)
+            .map_err(|e| ProverError::SetupError {
+                reason: e.to_string(),
+            })?;
+
+        // The setup only depends on the circuit's constraint shape, not on any concrete
+        // witness, so `CircomBuilder::setup` (no inputs pushed) is sufficient here.
+        let builder = CircomBuilder::new(cfg);
+        let setup_circuit = builder.setup();
+
+        info!("Running trusted setup for circuit: {}", name);
+        let mut rng = thread_rng();
+        let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(setup_circuit, &mut rng)
+            .map_err(|e| ProverError::SetupError {
+                reason: e.to_string(),
+            })?;
+
+        self.persist_keys(name, &pk, &vk)?;
+        self.cache_proving_key(circuit, pk);
+
+        Ok(())
     }
 
-    /// Parse snarkjs zkey file format
-    fn parse_zkey(_data: &[u8]) -> Result<ProvingKey<Bn254>> {
-        // In production, use ark-circom's built-in zkey parser
-        // For now, return a placeholder error indicating setup is needed
-        Err(ProverError::SetupError {
-            reason: "ZKey parsing not implemented - use CircomBuilder for full integration".into(),
-        })
+    /// Load a previously generated proving key from `build_dir` into the in-memory cache.
+    ///
+    /// Use this (instead of [`generate_keys`](Self::generate_keys)) when the keys were
+    /// already generated by an earlier run or a separate setup command.
+    #[instrument(skip(self), fields(circuit = %circuit.file_name()))]
+    pub fn load_keys(&mut self, circuit: &Circuit) -> Result<()> {
+        let pk = self.load_proving_key(circuit)?;
+        self.cache_proving_key(circuit, pk);
+        Ok(())
+    }
+
+    /// Load a proving key directly from a Groth16 `.zkey` artifact, following the
+    /// zerokit/semaphore-rs approach of building on [`ark_circom::read_zkey`] rather than
+    /// requiring a separate `proving_key.bin` produced by [`Self::generate_keys`]. Keeps
+    /// proving and verifying keys in sync from one file - see
+    /// [`crate::verifier::ComplianceVerifier::from_zkey`] for the verifier-side counterpart
+    /// reading the same artifact's verifying half.
+    #[instrument(skip(self), fields(circuit = %circuit.file_name()))]
+    pub fn load_keys_from_zkey(&mut self, circuit: &Circuit, path: impl AsRef<str>) -> Result<()> {
+        let path = path.as_ref();
+        debug!("Loading zkey from: {}", path);
+
+        let mut file = fs::File::open(path)?;
+        let (pk, _matrices) = read_zkey(&mut file).map_err(|e| ProverError::InvalidProofFormat {
+            reason: format!("failed to read zkey {path}: {e}"),
+        })?;
+
+        self.cache_proving_key(circuit, pk);
+        Ok(())
+    }
+
+    /// Serialize and write a proving/verifying key pair into `build_dir/{name}/`.
+    ///
+    /// Writes the verifying key twice, in both formats the crate reads back: the
+    /// ark-serialize `verifying_key.bin` (compact, used by nothing yet but kept for
+    /// parity with `proving_key.bin`) and the snarkjs-shaped `verification_key.json`
+    /// that [`crate::verifier::ComplianceVerifier`]/[`crate::verifier::AggregationVerifier`]
+    /// actually load on disk - without the latter, `generate_keys` followed by a verifier
+    /// built over the same `build_dir` would fail with `CircuitNotFound`.
+    fn persist_keys(
+        &self,
+        name: &str,
+        pk: &ProvingKey<Bn254>,
+        vk: &VerifyingKey<Bn254>,
+    ) -> Result<()> {
+        let circuit_dir = format!("{}/{}", self.build_dir, name);
+        fs::create_dir_all(&circuit_dir)?;
+
+        let pk_path = format!("{circuit_dir}/proving_key.bin");
+        let vk_path = format!("{circuit_dir}/verifying_key.bin");
+        let vk_json_path = format!("{circuit_dir}/verification_key.json");
+
+        let mut pk_bytes = Vec::new();
+        pk.serialize_compressed(&mut pk_bytes)?;
+        fs::write(&pk_path, &pk_bytes)?;
+
+        let mut vk_bytes = Vec::new();
+        vk.serialize_compressed(&mut vk_bytes)?;
+        fs::write(&vk_path, &vk_bytes)?;
+
+        let vk_json = VerifyingKeyJson::from_verifying_key(vk)?;
+        fs::write(&vk_json_path, serde_json::to_string(&vk_json)?)?;
+
+        debug!(
+            "Persisted proving key to {}, verifying key to {} and {}",
+            pk_path, vk_path, vk_json_path
+        );
+        Ok(())
+    }
+
+    /// Store a loaded/generated proving key in the field matching `circuit`.
+    fn cache_proving_key(&mut self, circuit: &Circuit, pk: ProvingKey<Bn254>) {
+        match circuit {
+            Circuit::Threshold => self.threshold_pk = Some(pk),
+            Circuit::Range => self.range_pk = Some(pk),
+            Circuit::Tier => self.tier_pk = Some(pk),
+            Circuit::Membership => self.membership_pk = Some(pk),
+            Circuit::Rln => self.rln_pk = Some(pk),
+            Circuit::Aggregation => self.aggregation_pk = Some(pk),
+        }
+    }
+
+    /// Get the cached proving key for `circuit`, if one has been generated or loaded.
+    fn cached_proving_key(&self, circuit: &Circuit) -> Option<&ProvingKey<Bn254>> {
+        match circuit {
+            Circuit::Threshold => self.threshold_pk.as_ref(),
+            Circuit::Range => self.range_pk.as_ref(),
+            Circuit::Tier => self.tier_pk.as_ref(),
+            Circuit::Membership => self.membership_pk.as_ref(),
+            Circuit::Rln => self.rln_pk.as_ref(),
+            Circuit::Aggregation => self.aggregation_pk.as_ref(),
+        }
+    }
+
+    /// Load proving key for a circuit from its persisted `proving_key.bin`
+    fn load_proving_key(&self, circuit: &Circuit) -> Result<ProvingKey<Bn254>> {
+        let name = circuit.file_name();
+        let pk_path = format!("{}/{}/proving_key.bin", self.build_dir, name);
+
+        if !Path::new(&pk_path).exists() {
+            return Err(ProverError::CircuitNotFound { path: pk_path });
+        }
+
+        debug!("Loading proving key from: {}", pk_path);
+
+        let pk_bytes = fs::read(&pk_path)?;
+        let pk = ProvingKey::<Bn254>::deserialize_compressed(&pk_bytes[..])?;
+
+        Ok(pk)
     }
 
     /// Build circuit with inputs and generate proof
@@ -113,6 +290,17 @@ impl ComplianceProver {
             });
         }
 
+        let pk = match self.cached_proving_key(circuit) {
+            Some(pk) => pk,
+            None => {
+                return Err(ProverError::SetupError {
+                    reason: format!(
+                        "no proving key cached for {name}; call generate_keys or load_keys first"
+                    ),
+                })
+            }
+        };
+
         info!("Building circuit: {}", name);
         let start = Instant::now();
 
@@ -124,7 +312,7 @@ impl ComplianceProver {
 
         // Build circuit with inputs
         let mut builder = CircomBuilder::new(cfg);
-        
+
         for (signal_name, values) in inputs {
             builder.push_input(&signal_name, values);
         }
@@ -137,16 +325,11 @@ impl ComplianceProver {
         let witness_time = start.elapsed();
         debug!("Witness generated in {:?}", witness_time);
 
-        // Generate proof
+        // Generate proof using the cached, one-time-setup proving key
         let prove_start = Instant::now();
         let mut rng = thread_rng();
 
-        let (pk, _vk) = Groth16::<Bn254>::circuit_specific_setup(circuit.clone(), &mut rng)
-            .map_err(|e| ProverError::SetupError {
-                reason: e.to_string(),
-            })?;
-
-        let proof = Groth16::<Bn254>::prove(&pk, circuit.clone(), &mut rng)
+        let proof = Groth16::<Bn254>::prove(pk, circuit.clone(), &mut rng)
             .map_err(|e| ProverError::ProofGenerationFailed {
                 reason: e.to_string(),
             })?;
@@ -186,7 +369,7 @@ impl ComplianceProver {
             &input.entity_hash[..8.min(input.entity_hash.len())]
         );
 
-        self.build_and_prove(&Circuit::Threshold, input.to_circuit_input())
+        self.build_and_prove(&Circuit::Threshold, input.to_circuit_input()?)
     }
 
     /// Generate a range compliance proof
@@ -203,7 +386,7 @@ impl ComplianceProver {
             &input.entity_hash[..8.min(input.entity_hash.len())]
         );
 
-        self.build_and_prove(&Circuit::Range, input.to_circuit_input())
+        self.build_and_prove(&Circuit::Range, input.to_circuit_input()?)
     }
 
     /// Generate a tier membership proof
@@ -219,11 +402,89 @@ impl ComplianceProver {
             &input.entity_hash[..8.min(input.entity_hash.len())]
         );
 
-        self.build_and_prove(&Circuit::Tier, input.to_circuit_input())
+        self.build_and_prove(&Circuit::Tier, input.to_circuit_input()?)
+    }
+
+    /// Generate an approved-entity allowlist membership proof
+    ///
+    /// Proves that `entity_hash` is a leaf of the Merkle tree rooted at `root`, without
+    /// revealing which leaf.
+    #[instrument(skip(self, input))]
+    pub fn prove_membership(&self, input: &MembershipInput) -> Result<ProofWithPublicInputs> {
+        input.validate()?;
+
+        info!(
+            "Generating membership proof: root prefix: {}..., entity_hash prefix: {}...",
+            &input.root[..8.min(input.root.len())],
+            &input.entity_hash[..8.min(input.entity_hash.len())]
+        );
+
+        self.build_and_prove(&Circuit::Membership, input.to_circuit_input()?)
+    }
+
+    /// Generate an RLN-style rate-limiting nullifier proof
+    ///
+    /// Proves knowledge of an identity secret underlying the public `share_x`/`share_y`/
+    /// `nullifier` triple for the given `epoch`, without revealing the secret. A second
+    /// proof from the same entity in the same epoch can be detected and its secret
+    /// recovered off-circuit via [`crate::rln::recover_secret`].
+    #[instrument(skip(self, input))]
+    pub fn prove_rln(&self, input: &RlnInput) -> Result<ProofWithPublicInputs> {
+        input.validate()?;
+
+        info!("Generating RLN proof for epoch: {}", input.epoch);
+
+        self.build_and_prove(&Circuit::Rln, input.to_circuit_input()?)
+    }
+
+    /// Fold several independent compliance proofs (threshold, range, tier, or any mix) into
+    /// one aggregated Groth16 proof, so a relying party checks one proof and one verifying
+    /// key instead of one per `inner` proof.
+    ///
+    /// The aggregate's sole public input is the Poseidon commitment over `inner`'s
+    /// flattened, zero-padded public signals (see [`crate::circuits::AggregationCircuit`]);
+    /// [`crate::verifier::AggregationVerifier::verify_batch`] recomputes and checks it.
+    /// This does not re-verify `inner`'s own pairing checks - callers must already have
+    /// verified each inner proof before aggregating it.
+    #[instrument(skip(self, inner), fields(count = inner.len()))]
+    pub fn prove_aggregation(&self, inner: &[ProofWithPublicInputs]) -> Result<ProofWithPublicInputs> {
+        let pk = match self.cached_proving_key(&Circuit::Aggregation) {
+            Some(pk) => pk,
+            None => {
+                return Err(ProverError::SetupError {
+                    reason: "no proving key cached for aggregation_proof; call generate_keys or load_keys first".into(),
+                })
+            }
+        };
+
+        let signals: Vec<Fr> = inner.iter().flat_map(|p| p.public_inputs.iter().copied()).collect();
+        let circuit = AggregationCircuit::new(&signals).ok_or_else(|| ProverError::InvalidInput {
+            field: "inner".into(),
+            value: signals.len().to_string(),
+            expected: format!("at most {} flattened public signals", crate::circuits::MAX_AGGREGATE_SIGNALS),
+        })?;
+        let commitment = circuit.commitment;
+
+        info!("Generating aggregation proof over {} inner proofs", inner.len());
+        let mut rng = thread_rng();
+        let proof = Groth16::<Bn254>::prove(pk, circuit, &mut rng)
+            .map_err(|e| ProverError::ProofGenerationFailed {
+                reason: e.to_string(),
+            })?;
+
+        Ok(ProofWithPublicInputs::new(
+            Proof::new(proof),
+            vec![commitment],
+            Circuit::Aggregation.file_name().to_string(),
+        ))
     }
 }
 
 /// Convenience function to generate a threshold proof
+///
+/// Runs the trusted setup for the threshold circuit on every call; prefer constructing a
+/// [`ComplianceProver`] directly and calling [`ComplianceProver::generate_keys`] once, then
+/// [`ComplianceProver::prove_threshold`] per proof.
 pub fn prove_compliance_threshold(
     build_dir: &str,
     score: u64,
@@ -231,7 +492,9 @@ pub fn prove_compliance_threshold(
     entity_hash: &str,
     salt: &str,
 ) -> Result<ProofWithPublicInputs> {
-    let prover = ComplianceProver::new(build_dir)?;
+    let mut prover = ComplianceProver::new(build_dir)?;
+    prover.generate_keys(&Circuit::Threshold)?;
+
     let input = ThresholdInput {
         threshold,
         entity_hash: entity_hash.to_string(),
@@ -250,6 +513,35 @@ mod tests {
         assert_eq!(Circuit::Threshold.file_name(), "compliance_threshold");
         assert_eq!(Circuit::Range.file_name(), "range_proof");
         assert_eq!(Circuit::Tier.file_name(), "tier_membership");
+        assert_eq!(Circuit::Membership.file_name(), "membership_proof");
+        assert_eq!(Circuit::Rln.file_name(), "rln_proof");
+        assert_eq!(Circuit::Aggregation.file_name(), "aggregation_proof");
+    }
+
+    #[test]
+    fn test_circuit_from_file_name_roundtrips() {
+        for circuit in [
+            Circuit::Threshold,
+            Circuit::Range,
+            Circuit::Tier,
+            Circuit::Membership,
+            Circuit::Rln,
+            Circuit::Aggregation,
+        ] {
+            assert_eq!(Circuit::from_file_name(circuit.file_name()).unwrap().file_name(), circuit.file_name());
+        }
+        assert!(Circuit::from_file_name("not_a_real_circuit").is_none());
     }
-}
 
+    #[test]
+    fn test_load_keys_from_zkey_missing_file_errors() {
+        let dir = std::env::temp_dir().join("civium-load-keys-from-zkey-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut prover = ComplianceProver::new(dir.to_str().unwrap()).unwrap();
+
+        let result = prover.load_keys_from_zkey(&Circuit::Threshold, "does_not_exist.zkey");
+
+        assert!(result.is_err());
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,98 @@
+//! RLN-style rate-limiting nullifier for per-epoch proof deduplication
+//!
+//! Modeled on the [RLN](https://rate-limiting-nullifier.github.io/rln-docs/) construction:
+//! an entity's secret `a0` defines, together with the public `epoch`, a degree-1 line
+//! `y = a0 + a1 * x` where `a1 = Poseidon(a0, epoch)`. Each proof in that epoch evaluates
+//! the line at a different public `share_x` to produce a Shamir share `share_y`. A single
+//! proof per epoch leaks nothing about `a0`, but two proofs in the *same* epoch give two
+//! points on the same line, letting anyone reconstruct `a0` with [`recover_secret`] and
+//! flag the entity as having double-proved.
+
+use ark_bn254::Fr;
+use ark_ff::Zero;
+
+use crate::error::{ProverError, Result};
+
+/// Recover an entity's secret `a0` from two Shamir shares `(x, y)` produced in the same
+/// epoch, i.e. two points on the same degree-1 line `y = a0 + a1 * x`.
+///
+/// Given distinct `x1 != x2`, solves for the line's slope `a1 = (y2 - y1) / (x2 - x1)` and
+/// then its intercept `a0 = y1 - a1 * x1`. Errors if `x1 == x2`: two submitted proofs
+/// landing on the same `share_x` is untrusted-input territory (duplicate or adversarial
+/// resubmission), not a bug, so it must not panic the caller via a zero-divisor `Fr` division.
+pub fn recover_secret(share1: (Fr, Fr), share2: (Fr, Fr)) -> Result<Fr> {
+    let (x1, y1) = share1;
+    let (x2, y2) = share2;
+
+    let dx = x2 - x1;
+    if dx.is_zero() {
+        return Err(ProverError::InvalidInput {
+            field: "share_x".into(),
+            value: x1.to_string(),
+            expected: "two shares with distinct share_x values".into(),
+        });
+    }
+
+    let slope = (y2 - y1) / dx;
+    Ok(y1 - slope * x1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circuits::RlnCircuit;
+
+    #[test]
+    fn test_recover_secret_from_two_shares() {
+        let a0 = Fr::from(42u64);
+        let epoch = Fr::from(7u64);
+
+        let share1 = RlnCircuit::new(epoch, Fr::from(11u64), a0);
+        let share2 = RlnCircuit::new(epoch, Fr::from(22u64), a0);
+
+        let recovered = recover_secret(
+            (share1.share_x, share1.share_y),
+            (share2.share_x, share2.share_y),
+        )
+        .unwrap();
+
+        assert_eq!(recovered, a0);
+    }
+
+    #[test]
+    fn test_recover_secret_differs_across_epochs() {
+        // Two shares from *different* epochs lie on different lines, so naively applying
+        // the same-epoch recovery formula must not recover the real secret.
+        let a0 = Fr::from(42u64);
+
+        let share1 = RlnCircuit::new(Fr::from(7u64), Fr::from(11u64), a0);
+        let share2 = RlnCircuit::new(Fr::from(8u64), Fr::from(22u64), a0);
+
+        let recovered = recover_secret(
+            (share1.share_x, share1.share_y),
+            (share2.share_x, share2.share_y),
+        )
+        .unwrap();
+
+        assert_ne!(recovered, a0);
+    }
+
+    #[test]
+    fn test_recover_secret_rejects_colliding_share_x() {
+        // Two proofs that happen to (or are crafted to) reuse the same `share_x` give no
+        // second line-point to solve with; the naive formula would divide by zero instead
+        // of reporting the collision.
+        let a0 = Fr::from(42u64);
+        let epoch = Fr::from(7u64);
+
+        let share1 = RlnCircuit::new(epoch, Fr::from(11u64), a0);
+        let share2 = RlnCircuit::new(epoch, Fr::from(11u64), a0);
+
+        let result = recover_secret(
+            (share1.share_x, share1.share_y),
+            (share2.share_x, share2.share_y),
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,204 @@
+//! On-chain Solidity Groth16 verifier generation
+//!
+//! [`generate_verifier_contract`] takes a verifying key and emits a complete, deployable
+//! `.sol` file with the key baked in as constants, so callers can go straight from a
+//! proving-key setup to an on-chain verifier without hand-writing Solidity or relying on
+//! [`crate::proof::SolidityCalldata::to_solidity_call`] against a contract they already
+//! have. The generated pairing check uses the BN254 precompiles directly (`ecAdd` at
+//! `0x06`, `ecMul` at `0x07`, `ecPairing` at `0x08`) and matches the G2 coordinate
+//! ordering already used by [`crate::proof::Proof::to_solidity_calldata`].
+
+use ark_bn254::Bn254;
+use ark_groth16::VerifyingKey;
+
+use crate::proof::Proof;
+
+/// Render a deployable Solidity Groth16 verifier contract for `vk`.
+///
+/// The returned source hardcodes `vk`'s `alpha`/`beta`/`gamma`/`delta` points and its
+/// `IC` basis as `uint256` constants, so the contract needs no verifying-key input at
+/// call time — only the proof (`a`, `b`, `c`) and the public inputs.
+pub fn generate_verifier_contract(vk: &VerifyingKey<Bn254>) -> String {
+    let alpha = Proof::<Bn254>::g1_to_uint256(&vk.alpha_g1);
+    let beta = Proof::<Bn254>::g2_to_uint256(&vk.beta_g2);
+    let gamma = Proof::<Bn254>::g2_to_uint256(&vk.gamma_g2);
+    let delta = Proof::<Bn254>::g2_to_uint256(&vk.delta_g2);
+    let ic: Vec<[String; 2]> = vk.gamma_abc_g1.iter().map(Proof::<Bn254>::g1_to_uint256).collect();
+
+    let ic_assignments = ic
+        .iter()
+        .enumerate()
+        .map(|(i, p)| format!("        vk.IC[{i}] = Pairing.G1Point({}, {});", p[0], p[1]))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r#"// SPDX-License-Identifier: MIT
+// Auto-generated Groth16 verifier for a Civium compliance circuit. Do not edit by hand.
+pragma solidity ^0.8.19;
+
+library Pairing {{
+    struct G1Point {{
+        uint256 X;
+        uint256 Y;
+    }}
+
+    // Encoded as (X, Y) where X = x0 * z + x1, i.e. big-endian coefficient order.
+    struct G2Point {{
+        uint256[2] X;
+        uint256[2] Y;
+    }}
+
+    uint256 constant PRIME_Q =
+        21888242871839275222246405745257275088696311157297823662689037894645226208583;
+
+    function negate(G1Point memory p) internal pure returns (G1Point memory) {{
+        if (p.X == 0 && p.Y == 0) {{
+            return G1Point(0, 0);
+        }}
+        return G1Point(p.X, PRIME_Q - (p.Y % PRIME_Q));
+    }}
+
+    function addition(G1Point memory p1, G1Point memory p2) internal view returns (G1Point memory r) {{
+        uint256[4] memory input;
+        input[0] = p1.X;
+        input[1] = p1.Y;
+        input[2] = p2.X;
+        input[3] = p2.Y;
+        bool success;
+        assembly {{
+            success := staticcall(gas(), 0x06, input, 0x80, r, 0x40)
+        }}
+        require(success, "pairing-add-failed");
+    }}
+
+    function scalarMul(G1Point memory p, uint256 s) internal view returns (G1Point memory r) {{
+        uint256[3] memory input;
+        input[0] = p.X;
+        input[1] = p.Y;
+        input[2] = s;
+        bool success;
+        assembly {{
+            success := staticcall(gas(), 0x07, input, 0x60, r, 0x40)
+        }}
+        require(success, "pairing-mul-failed");
+    }}
+
+    function pairing(G1Point[] memory p1, G2Point[] memory p2) internal view returns (bool) {{
+        require(p1.length == p2.length, "pairing-length-mismatch");
+        uint256 elements = p1.length;
+        uint256 inputSize = elements * 6;
+        uint256[] memory input = new uint256[](inputSize);
+        for (uint256 i = 0; i < elements; i++) {{
+            input[i * 6 + 0] = p1[i].X;
+            input[i * 6 + 1] = p1[i].Y;
+            input[i * 6 + 2] = p2[i].X[0];
+            input[i * 6 + 3] = p2[i].X[1];
+            input[i * 6 + 4] = p2[i].Y[0];
+            input[i * 6 + 5] = p2[i].Y[1];
+        }}
+        uint256[1] memory out;
+        bool success;
+        assembly {{
+            success := staticcall(gas(), 0x08, add(input, 0x20), mul(inputSize, 0x20), out, 0x20)
+        }}
+        require(success, "pairing-opcode-failed");
+        return out[0] != 0;
+    }}
+}}
+
+contract Verifier {{
+    struct VerifyingKey {{
+        Pairing.G1Point alpha;
+        Pairing.G2Point beta;
+        Pairing.G2Point gamma;
+        Pairing.G2Point delta;
+        Pairing.G1Point[] IC;
+    }}
+
+    function verifyingKey() internal pure returns (VerifyingKey memory vk) {{
+        vk.alpha = Pairing.G1Point({alpha0}, {alpha1});
+        vk.beta = Pairing.G2Point([{beta00}, {beta01}], [{beta10}, {beta11}]);
+        vk.gamma = Pairing.G2Point([{gamma00}, {gamma01}], [{gamma10}, {gamma11}]);
+        vk.delta = Pairing.G2Point([{delta00}, {delta01}], [{delta10}, {delta11}]);
+        vk.IC = new Pairing.G1Point[]({ic_len});
+{ic_assignments}
+    }}
+
+    function verifyProof(
+        uint[2] memory a,
+        uint[2][2] memory b,
+        uint[2] memory c,
+        uint[] memory input
+    ) public view returns (bool) {{
+        VerifyingKey memory vk = verifyingKey();
+        require(input.length + 1 == vk.IC.length, "verifier-bad-input-length");
+
+        Pairing.G1Point memory vkX = vk.IC[0];
+        for (uint256 i = 0; i < input.length; i++) {{
+            vkX = Pairing.addition(vkX, Pairing.scalarMul(vk.IC[i + 1], input[i]));
+        }}
+
+        Pairing.G1Point[] memory p1 = new Pairing.G1Point[](4);
+        Pairing.G2Point[] memory p2 = new Pairing.G2Point[](4);
+
+        p1[0] = Pairing.negate(Pairing.G1Point(a[0], a[1]));
+        p2[0] = Pairing.G2Point([b[0][0], b[0][1]], [b[1][0], b[1][1]]);
+
+        p1[1] = vk.alpha;
+        p2[1] = vk.beta;
+
+        p1[2] = vkX;
+        p2[2] = vk.gamma;
+
+        p1[3] = Pairing.G1Point(c[0], c[1]);
+        p2[3] = vk.delta;
+
+        return Pairing.pairing(p1, p2);
+    }}
+}}
+"#,
+        alpha0 = alpha[0],
+        alpha1 = alpha[1],
+        beta00 = beta[0][0],
+        beta01 = beta[0][1],
+        beta10 = beta[1][0],
+        beta11 = beta[1][1],
+        gamma00 = gamma[0][0],
+        gamma01 = gamma[0][1],
+        gamma10 = gamma[1][0],
+        gamma11 = gamma[1][1],
+        delta00 = delta[0][0],
+        delta01 = delta[0][1],
+        delta10 = delta[1][0],
+        delta11 = delta[1][1],
+        ic_len = ic.len(),
+        ic_assignments = ic_assignments,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+    use ark_groth16::Groth16;
+    use ark_snark::SNARK;
+    use ark_std::rand::thread_rng;
+
+    use crate::circuits::ThresholdCircuit;
+
+    #[test]
+    fn test_generate_verifier_contract_embeds_vk_constants() {
+        let circuit = ThresholdCircuit::new(8000, Fr::from(123456789u64), 8500, Fr::from(987654321u64));
+
+        let mut rng = thread_rng();
+        let (_, vk) = Groth16::<Bn254>::circuit_specific_setup(circuit, &mut rng).unwrap();
+
+        let source = generate_verifier_contract(&vk);
+
+        assert!(source.contains("contract Verifier"));
+        assert!(source.contains("function verifyProof"));
+        assert!(source.contains(&vk.alpha_g1.x.to_string()));
+        assert!(source.contains(&format!("new Pairing.G1Point[]({})", vk.gamma_abc_g1.len())));
+    }
+}
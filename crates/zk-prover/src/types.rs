@@ -1,8 +1,17 @@
 //! Input types for ZK-SNARK circuits
 
+use ark_bn254::Fr;
+use ark_ff::{BigInteger, PrimeField};
 use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
 
+use crate::poseidon::{string_to_fr, PoseidonHasher};
+
+/// Convert a scalar field element to the big-endian [`BigUint`] circuit inputs are pushed as.
+fn fr_to_biguint(f: Fr) -> BigUint {
+    BigUint::from_bytes_be(&f.into_bigint().to_bytes_be())
+}
+
 /// Maximum valid score (1.0000 in fixed-point)
 pub const MAX_SCORE: u64 = 10000;
 
@@ -57,22 +66,29 @@ impl ThresholdInput {
         Ok(())
     }
 
+    /// Poseidon commitment binding the private `score` to this proof:
+    /// `Poseidon(score, salt, entity_hash)`. Recompute this off-chain to check a proof's
+    /// published commitment (see [`crate::proof::ProofWithPublicInputs::score_commitment`])
+    /// against a score you already know.
+    pub fn commitment(&self) -> Result<Fr, crate::ProverError> {
+        let salt = string_to_fr(&self.salt)?;
+        let entity_hash = string_to_fr(&self.entity_hash)?;
+        Ok(PoseidonHasher::new().compute_commitment(self.score, &salt, &entity_hash))
+    }
+
     /// Convert to circuit input format
-    pub fn to_circuit_input(&self) -> Vec<(String, Vec<BigUint>)> {
-        vec![
+    pub fn to_circuit_input(&self) -> Result<Vec<(String, Vec<BigUint>)>, crate::ProverError> {
+        let entity_hash = string_to_fr(&self.entity_hash)?;
+        let salt = string_to_fr(&self.salt)?;
+        let commitment = PoseidonHasher::new().compute_commitment(self.score, &salt, &entity_hash);
+
+        Ok(vec![
             ("threshold".into(), vec![BigUint::from(self.threshold)]),
-            (
-                "entityHash".into(),
-                vec![BigUint::parse_bytes(self.entity_hash.as_bytes(), 10)
-                    .unwrap_or_else(|| BigUint::from(0u64))],
-            ),
+            ("entityHash".into(), vec![fr_to_biguint(entity_hash)]),
             ("score".into(), vec![BigUint::from(self.score)]),
-            (
-                "salt".into(),
-                vec![BigUint::parse_bytes(self.salt.as_bytes(), 10)
-                    .unwrap_or_else(|| BigUint::from(0u64))],
-            ),
-        ]
+            ("salt".into(), vec![fr_to_biguint(salt)]),
+            ("commitment".into(), vec![fr_to_biguint(commitment)]),
+        ])
     }
 }
 
@@ -116,23 +132,30 @@ impl RangeInput {
         Ok(())
     }
 
+    /// Poseidon commitment binding the private `score` to this proof:
+    /// `Poseidon(score, salt, entity_hash)`. Recompute this off-chain to check a proof's
+    /// published commitment (see [`crate::proof::ProofWithPublicInputs::score_commitment`])
+    /// against a score you already know.
+    pub fn commitment(&self) -> Result<Fr, crate::ProverError> {
+        let salt = string_to_fr(&self.salt)?;
+        let entity_hash = string_to_fr(&self.entity_hash)?;
+        Ok(PoseidonHasher::new().compute_commitment(self.score, &salt, &entity_hash))
+    }
+
     /// Convert to circuit input format
-    pub fn to_circuit_input(&self) -> Vec<(String, Vec<BigUint>)> {
-        vec![
+    pub fn to_circuit_input(&self) -> Result<Vec<(String, Vec<BigUint>)>, crate::ProverError> {
+        let entity_hash = string_to_fr(&self.entity_hash)?;
+        let salt = string_to_fr(&self.salt)?;
+        let commitment = PoseidonHasher::new().compute_commitment(self.score, &salt, &entity_hash);
+
+        Ok(vec![
             ("minScore".into(), vec![BigUint::from(self.min_score)]),
             ("maxScore".into(), vec![BigUint::from(self.max_score)]),
-            (
-                "entityHash".into(),
-                vec![BigUint::parse_bytes(self.entity_hash.as_bytes(), 10)
-                    .unwrap_or_else(|| BigUint::from(0u64))],
-            ),
+            ("entityHash".into(), vec![fr_to_biguint(entity_hash)]),
             ("score".into(), vec![BigUint::from(self.score)]),
-            (
-                "salt".into(),
-                vec![BigUint::parse_bytes(self.salt.as_bytes(), 10)
-                    .unwrap_or_else(|| BigUint::from(0u64))],
-            ),
-        ]
+            ("salt".into(), vec![fr_to_biguint(salt)]),
+            ("commitment".into(), vec![fr_to_biguint(commitment)]),
+        ])
     }
 }
 
@@ -187,22 +210,131 @@ impl TierInput {
         }
     }
 
+    /// Poseidon commitment binding the private `score` to this proof:
+    /// `Poseidon(score, salt, entity_hash)`. Recompute this off-chain to check a proof's
+    /// published commitment (see [`crate::proof::ProofWithPublicInputs::score_commitment`])
+    /// against a score you already know.
+    pub fn commitment(&self) -> Result<Fr, crate::ProverError> {
+        let salt = string_to_fr(&self.salt)?;
+        let entity_hash = string_to_fr(&self.entity_hash)?;
+        Ok(PoseidonHasher::new().compute_commitment(self.score, &salt, &entity_hash))
+    }
+
     /// Convert to circuit input format
-    pub fn to_circuit_input(&self) -> Vec<(String, Vec<BigUint>)> {
-        vec![
+    pub fn to_circuit_input(&self) -> Result<Vec<(String, Vec<BigUint>)>, crate::ProverError> {
+        let entity_hash = string_to_fr(&self.entity_hash)?;
+        let salt = string_to_fr(&self.salt)?;
+        let commitment = PoseidonHasher::new().compute_commitment(self.score, &salt, &entity_hash);
+
+        Ok(vec![
             ("targetTier".into(), vec![BigUint::from(self.target_tier)]),
-            (
-                "entityHash".into(),
-                vec![BigUint::parse_bytes(self.entity_hash.as_bytes(), 10)
-                    .unwrap_or_else(|| BigUint::from(0u64))],
-            ),
+            ("entityHash".into(), vec![fr_to_biguint(entity_hash)]),
             ("score".into(), vec![BigUint::from(self.score)]),
-            (
-                "salt".into(),
-                vec![BigUint::parse_bytes(self.salt.as_bytes(), 10)
-                    .unwrap_or_else(|| BigUint::from(0u64))],
-            ),
-        ]
+            ("salt".into(), vec![fr_to_biguint(salt)]),
+            ("commitment".into(), vec![fr_to_biguint(commitment)]),
+        ])
+    }
+}
+
+/// Input for an approved-entity allowlist membership proof
+///
+/// Proves: `entity_hash` is a leaf of the Merkle tree rooted at `root`, without revealing
+/// which leaf. See [`crate::merkle::PoseidonTree`] for building `root`/`siblings`/`path_bits`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MembershipInput {
+    /// Merkle root of the approved-entity allowlist (public)
+    pub root: String,
+    /// Hash of entity identifier, the leaf being proven (public)
+    pub entity_hash: String,
+    /// Sibling hashes from leaf to root (private)
+    pub siblings: Vec<String>,
+    /// Whether the tracked node is the right child at each level (private)
+    pub path_bits: Vec<bool>,
+}
+
+impl MembershipInput {
+    /// Validate input values
+    pub fn validate(&self) -> Result<(), crate::ProverError> {
+        if self.siblings.len() != self.path_bits.len() {
+            return Err(crate::ProverError::InvalidInput {
+                field: "siblings".into(),
+                value: self.siblings.len().to_string(),
+                expected: format!("same length as path_bits ({})", self.path_bits.len()),
+            });
+        }
+        Ok(())
+    }
+
+    /// Convert to circuit input format
+    pub fn to_circuit_input(&self) -> Result<Vec<(String, Vec<BigUint>)>, crate::ProverError> {
+        let root = fr_to_biguint(string_to_fr(&self.root)?);
+        let entity_hash = fr_to_biguint(string_to_fr(&self.entity_hash)?);
+
+        let siblings = self
+            .siblings
+            .iter()
+            .map(|s| string_to_fr(s).map(fr_to_biguint))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let path_bits = self
+            .path_bits
+            .iter()
+            .map(|&bit| BigUint::from(u8::from(bit)))
+            .collect();
+
+        Ok(vec![
+            ("root".into(), vec![root]),
+            ("entityHash".into(), vec![entity_hash]),
+            ("siblings".into(), siblings),
+            ("pathBits".into(), path_bits),
+        ])
+    }
+}
+
+/// Input for an RLN-style rate-limiting nullifier proof
+///
+/// Proves knowledge of an identity secret `a0` whose degree-1 line (slope derived from
+/// `a0` and `epoch`) evaluates to `share_y` at `share_x`, while publishing a `nullifier`
+/// that lets verifiers detect two proofs from the same entity in the same epoch. See
+/// [`crate::rln::recover_secret`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RlnInput {
+    /// Epoch identifier the proof is scoped to (public)
+    pub epoch: String,
+    /// Per-proof Shamir share x-coordinate, e.g. `Poseidon(signal)` (public)
+    pub share_x: String,
+    /// Shamir share y-coordinate, `a0 + a1 * share_x` (public)
+    pub share_y: String,
+    /// Per-epoch nullifier, `Poseidon(a1)` (public)
+    pub nullifier: String,
+    /// Entity's identity secret (private)
+    pub a0: String,
+}
+
+impl RlnInput {
+    /// Validate input values
+    pub fn validate(&self) -> Result<(), crate::ProverError> {
+        if self.epoch.is_empty() {
+            return Err(crate::ProverError::InvalidInput {
+                field: "epoch".into(),
+                value: self.epoch.clone(),
+                expected: "non-empty epoch identifier".into(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Convert to circuit input format
+    pub fn to_circuit_input(&self) -> Result<Vec<(String, Vec<BigUint>)>, crate::ProverError> {
+        let parse = |s: &str| string_to_fr(s).map(fr_to_biguint);
+
+        Ok(vec![
+            ("epoch".into(), vec![parse(&self.epoch)?]),
+            ("shareX".into(), vec![parse(&self.share_x)?]),
+            ("shareY".into(), vec![parse(&self.share_y)?]),
+            ("nullifier".into(), vec![parse(&self.nullifier)?]),
+            ("a0".into(), vec![parse(&self.a0)?]),
+        ])
     }
 }
 
@@ -245,5 +377,69 @@ mod tests {
         assert_eq!(TierInput::tier_bounds(4), (5000, 6999));
         assert_eq!(TierInput::tier_bounds(5), (0, 4999));
     }
+
+    #[test]
+    fn test_threshold_commitment_is_deterministic_and_score_bound() {
+        let input = ThresholdInput {
+            threshold: 8000,
+            entity_hash: "123456789".into(),
+            score: 8500,
+            salt: "987654321".into(),
+        };
+        let commitment = input.commitment().unwrap();
+        assert_eq!(commitment, input.commitment().unwrap());
+
+        let different_score = ThresholdInput { score: 8501, ..input };
+        assert_ne!(commitment, different_score.commitment().unwrap());
+    }
+
+    #[test]
+    fn test_to_circuit_input_rejects_non_decimal_entity_hash() {
+        let input = ThresholdInput {
+            threshold: 8000,
+            entity_hash: "not-a-number".into(),
+            score: 8500,
+            salt: "987654321".into(),
+        };
+        assert!(input.to_circuit_input().is_err());
+    }
+
+    #[test]
+    fn test_membership_to_circuit_input_rejects_non_decimal_sibling() {
+        let input = MembershipInput {
+            root: "123456789".into(),
+            entity_hash: "987654321".into(),
+            siblings: vec!["111".into(), "not-a-number".into()],
+            path_bits: vec![false, true],
+        };
+        assert!(input.to_circuit_input().is_err());
+    }
+
+    #[test]
+    fn test_rln_to_circuit_input_rejects_non_decimal_a0() {
+        let input = RlnInput {
+            epoch: "7".into(),
+            share_x: "11".into(),
+            share_y: "42".into(),
+            nullifier: "99".into(),
+            a0: "not-a-number".into(),
+        };
+        assert!(input.to_circuit_input().is_err());
+    }
+
+    #[test]
+    fn test_to_circuit_input_includes_commitment_signal() {
+        let input = RangeInput {
+            min_score: 7000,
+            max_score: 9000,
+            entity_hash: "123456789".into(),
+            score: 8500,
+            salt: "987654321".into(),
+        };
+        let signals = input.to_circuit_input().unwrap();
+        let (name, values) = signals.last().unwrap();
+        assert_eq!(name, "commitment");
+        assert_eq!(values[0], fr_to_biguint(input.commitment().unwrap()));
+    }
 }
 
@@ -1,18 +1,27 @@
 //! ZK-SNARK proof verification
 
 use std::fs;
+use std::fs::File;
 use std::path::Path;
 
 use ark_bn254::{Bn254, Fr};
+use ark_circom::read_zkey;
+use ark_ec::pairing::Pairing;
+use ark_ec::CurveGroup;
 use ark_groth16::{Groth16, VerifyingKey};
 use ark_serialize::CanonicalDeserialize;
 use ark_snark::SNARK;
+use ark_std::rand::thread_rng;
+use ark_std::{UniformRand, Zero};
 use serde::Deserialize;
 use tracing::{debug, info, instrument};
 
+use crate::circuits::pad_aggregate_signals;
 use crate::error::{ProverError, Result};
-use crate::proof::{Proof, ProofWithPublicInputs};
+use crate::poseidon::PoseidonHasher;
+use crate::proof::{Proof, ProofJson, ProofWithPublicInputs, VerifyingKeyJson};
 use crate::prover::Circuit;
+use crate::solidity::generate_verifier_contract;
 
 /// ZK-SNARK proof verifier
 pub struct ComplianceVerifier {
@@ -41,33 +50,89 @@ impl ComplianceVerifier {
         })
     }
 
-    /// Load verification key from JSON file (snarkjs format)
+    /// Construct a verifier over the threshold/range/tier verification keys embedded into
+    /// the binary at compile time (see [`embedded`]), needing no `build_dir` on disk. This
+    /// is the path for targets like `wasm`/`python` where shipping a filesystem of JSON keys
+    /// is impractical.
+    #[cfg(feature = "embed-keys")]
+    pub fn embedded() -> Self {
+        Self {
+            build_dir: String::new(),
+            threshold_vk: None,
+            range_vk: None,
+            tier_vk: None,
+        }
+    }
+
+    /// Load verification key from JSON file (snarkjs format), preferring (in order) the
+    /// in-memory cache populated by [`Self::from_zkey`], then the compiled-in key from
+    /// [`embedded`] when the `embed-keys` feature is on, falling back to `build_dir` on disk
+    /// otherwise.
     fn load_verification_key(&self, circuit: &Circuit) -> Result<VerifyingKey<Bn254>> {
-        let name = circuit.file_name();
-        let vkey_path = format!("{}/{}/verification_key.json", self.build_dir, name);
+        if let Some(vk) = self.cached_verification_key(circuit) {
+            return Ok(vk.clone());
+        }
 
-        if !Path::new(&vkey_path).exists() {
-            return Err(ProverError::CircuitNotFound { path: vkey_path });
+        #[cfg(feature = "embed-keys")]
+        if let Some(result) = embedded::embedded_verification_key(circuit) {
+            return result;
         }
 
-        debug!("Loading verification key from: {}", vkey_path);
+        load_verification_key(&self.build_dir, circuit)
+    }
 
-        let vkey_json = fs::read_to_string(&vkey_path)?;
-        let vkey_data: VerificationKeyJson = serde_json::from_str(&vkey_json)?;
+    /// Store a loaded verification key in the field matching `circuit`.
+    fn cache_verification_key(&mut self, circuit: &Circuit, vk: VerifyingKey<Bn254>) -> Result<()> {
+        match circuit {
+            Circuit::Threshold => self.threshold_vk = Some(vk),
+            Circuit::Range => self.range_vk = Some(vk),
+            Circuit::Tier => self.tier_vk = Some(vk),
+            other => {
+                return Err(ProverError::InvalidProofFormat {
+                    reason: format!("no verification-key cache slot for {}", other.file_name()),
+                })
+            }
+        }
+        Ok(())
+    }
 
-        Self::parse_verification_key(&vkey_data)
+    /// Get the cached verification key for `circuit`, if one has been loaded.
+    fn cached_verification_key(&self, circuit: &Circuit) -> Option<&VerifyingKey<Bn254>> {
+        match circuit {
+            Circuit::Threshold => self.threshold_vk.as_ref(),
+            Circuit::Range => self.range_vk.as_ref(),
+            Circuit::Tier => self.tier_vk.as_ref(),
+            _ => None,
+        }
     }
 
-    /// Parse snarkjs verification key format
-    fn parse_verification_key(data: &VerificationKeyJson) -> Result<VerifyingKey<Bn254>> {
-        // In production, implement full parsing of snarkjs vkey format
-        // For now, return error indicating verification key needs parsing
-        Err(ProverError::SetupError {
-            reason: format!(
-                "VKey parsing not fully implemented - protocol: {}, curve: {}",
-                data.protocol, data.curve
-            ),
-        })
+    /// Load a verifying (and, internally, proving) key pair directly from a Groth16
+    /// `.zkey` artifact, following the zerokit/semaphore-rs approach of building on
+    /// [`ark_circom::read_zkey`] rather than requiring a separately-exported
+    /// `verification_key.json`. Caches the verifying key in the same slot
+    /// [`Self::load_verification_key`] checks, so later `verify_*` calls for `circuit`
+    /// use it without touching disk again.
+    ///
+    /// This keeps the proving and verifying keys in sync from one file, since both are
+    /// read out of the same `.zkey`; only the verifying half is kept here, as
+    /// [`ComplianceVerifier`] never needs the proving key.
+    #[instrument(skip(self), fields(circuit = %circuit.file_name()))]
+    pub fn from_zkey(&mut self, circuit: &Circuit, path: impl AsRef<str>) -> Result<()> {
+        if !matches!(circuit, Circuit::Threshold | Circuit::Range | Circuit::Tier) {
+            return Err(ProverError::InvalidProofFormat {
+                reason: format!("no verification-key cache slot for {}", circuit.file_name()),
+            });
+        }
+
+        let path = path.as_ref();
+        debug!("Loading zkey from: {}", path);
+
+        let mut file = File::open(path)?;
+        let (pk, _matrices) = read_zkey(&mut file).map_err(|e| ProverError::InvalidProofFormat {
+            reason: format!("failed to read zkey {path}: {e}"),
+        })?;
+
+        self.cache_verification_key(circuit, pk.vk)
     }
 
     /// Verify a threshold compliance proof
@@ -141,25 +206,345 @@ impl ComplianceVerifier {
             }
         })
     }
+
+    /// Export `circuit`'s verification key as a deployable Solidity Groth16 verifier.
+    ///
+    /// Loads the circuit's verifying key via [`Self::load_verification_key`] and writes
+    /// [`generate_verifier_contract`]'s output to `out_path`, so a smart contract can check
+    /// Civium compliance proofs on-chain instead of only off-chain through this verifier.
+    #[instrument(skip(self), fields(circuit = %circuit.file_name(), out_path = %out_path.as_ref()))]
+    pub fn export_evm_verifier(&self, circuit: &Circuit, out_path: impl AsRef<str>) -> Result<()> {
+        let vk = self.load_verification_key(circuit)?;
+        let source = generate_verifier_contract(&vk);
+        fs::write(out_path.as_ref(), source)?;
+        Ok(())
+    }
+
+    /// Batch-verify `N` proofs for the same `circuit` that share one verifying key but carry
+    /// different public inputs, à la Orchard's `BatchVerifier`.
+    ///
+    /// Loads the circuit's verifying key via [`Self::load_verification_key`] and delegates
+    /// to the standalone [`verify_batch`] function; see its docs for the batching technique.
+    #[instrument(skip(self, proofs), fields(circuit = %circuit.file_name(), count = proofs.len()))]
+    pub fn verify_batch(
+        &self,
+        circuit: &Circuit,
+        proofs: &[ProofWithPublicInputs],
+    ) -> Result<Vec<usize>> {
+        let vk = self.load_verification_key(circuit)?;
+        verify_batch(&vk, proofs)
+    }
+
+    /// All-or-nothing batch verification for the same `circuit`: `true` only if every proof
+    /// in `proofs` is valid.
+    ///
+    /// Loads the circuit's verifying key via [`Self::load_verification_key`] and delegates
+    /// to the standalone [`verify_batch_strict`] function. Unlike [`Self::verify_batch`], this never falls back
+    /// to per-proof verification on failure - use it when a single pass/fail answer is all the
+    /// caller needs.
+    #[instrument(skip(self, proofs), fields(circuit = %circuit.file_name(), count = proofs.len()))]
+    pub fn verify_batch_strict(
+        &self,
+        circuit: &Circuit,
+        proofs: &[ProofWithPublicInputs],
+    ) -> Result<bool> {
+        let vk = self.load_verification_key(circuit)?;
+        verify_batch_strict(&vk, proofs)
+    }
+
+    /// Verify `N` proofs for the same `circuit`, reporting pass/fail per proof.
+    ///
+    /// Runs the shared verifying key through the same randomized batch check as
+    /// [`Self::verify_batch`]; when the whole batch passes, every entry is `true`. On a
+    /// batch failure this falls back to verifying each proof individually so the result
+    /// vector pinpoints exactly which ones are invalid.
+    #[instrument(skip(self, proofs), fields(circuit = %circuit.file_name(), count = proofs.len()))]
+    pub fn verify_many(
+        &self,
+        circuit: &Circuit,
+        proofs: &[ProofWithPublicInputs],
+    ) -> Result<Vec<bool>> {
+        let failed = self.verify_batch(circuit, proofs)?;
+        let mut results = vec![true; proofs.len()];
+        for i in failed {
+            results[i] = false;
+        }
+        Ok(results)
+    }
+
+    /// Compute `vk_x = IC[0] + sum(public_i * IC[i+1])`, the linear combination of the
+    /// verifying key's `gamma_abc_g1` points with the proof's public inputs.
+    fn compute_vk_x(
+        vk: &VerifyingKey<Bn254>,
+        public_inputs: &[Fr],
+    ) -> Result<<Bn254 as Pairing>::G1> {
+        if public_inputs.len() + 1 != vk.gamma_abc_g1.len() {
+            return Err(ProverError::VerificationFailed {
+                reason: format!(
+                    "expected {} public inputs, got {}",
+                    vk.gamma_abc_g1.len() - 1,
+                    public_inputs.len()
+                ),
+            });
+        }
+
+        let mut vk_x: <Bn254 as Pairing>::G1 = vk.gamma_abc_g1[0].into_group();
+        for (input, ic) in public_inputs.iter().zip(vk.gamma_abc_g1.iter().skip(1)) {
+            vk_x += *ic * input;
+        }
+
+        Ok(vk_x)
+    }
 }
 
-/// snarkjs verification key JSON format
+/// snarkjs verification key JSON format.
+///
+/// `nPublic`/`vk_alphabeta_12` are optional: real snarkjs exports always carry them, but
+/// [`crate::prover::ComplianceProver::generate_keys`] persists the crate's own minimal
+/// [`VerifyingKeyJson`] shape (see `crate::proof`) to `verification_key.json`, which omits
+/// both - neither is needed to reconstruct a `VerifyingKey`, so neither is required:
+/// `nPublic` is checked against the `IC` length only when present, and `vk_alphabeta_12`
+/// is accepted but never read.
 #[derive(Debug, Deserialize)]
 struct VerificationKeyJson {
     protocol: String,
     curve: String,
-    #[serde(rename = "nPublic")]
-    n_public: u32,
+    #[serde(rename = "nPublic", default)]
+    n_public: Option<u32>,
     vk_alpha_1: Vec<String>,
     vk_beta_2: Vec<Vec<String>>,
     vk_gamma_2: Vec<Vec<String>>,
     vk_delta_2: Vec<Vec<String>>,
-    #[serde(rename = "vk_alphabeta_12")]
-    vk_alphabeta_12: Vec<Vec<Vec<String>>>,
+    #[serde(rename = "vk_alphabeta_12", default)]
+    vk_alphabeta_12: Option<Vec<Vec<Vec<String>>>>,
     #[serde(rename = "IC")]
     ic: Vec<Vec<String>>,
 }
 
+/// Load `circuit`'s verification key from `build_dir/{name}/verification_key.json`
+/// (snarkjs format). Shared by [`ComplianceVerifier`] and [`AggregationVerifier`], since
+/// both just need a `VerifyingKey<Bn254>` for whichever circuit name they're asking about.
+fn load_verification_key(build_dir: &str, circuit: &Circuit) -> Result<VerifyingKey<Bn254>> {
+    let name = circuit.file_name();
+    let vkey_path = format!("{build_dir}/{name}/verification_key.json");
+
+    if !Path::new(&vkey_path).exists() {
+        return Err(ProverError::CircuitNotFound { path: vkey_path });
+    }
+
+    debug!("Loading verification key from: {}", vkey_path);
+
+    let vkey_json = fs::read_to_string(&vkey_path)?;
+    let vkey_data: VerificationKeyJson = serde_json::from_str(&vkey_json)?;
+
+    parse_verification_key(&vkey_data)
+}
+
+/// Parse snarkjs verification key format
+fn parse_verification_key(data: &VerificationKeyJson) -> Result<VerifyingKey<Bn254>> {
+    if data.protocol != "groth16" {
+        return Err(ProverError::InvalidProofFormat {
+            reason: format!("unsupported protocol: {}", data.protocol),
+        });
+    }
+    if data.curve != "bn128" {
+        return Err(ProverError::InvalidProofFormat {
+            reason: format!("unsupported curve: {}", data.curve),
+        });
+    }
+    if let Some(n_public) = data.n_public {
+        if data.ic.len() != n_public as usize + 1 {
+            return Err(ProverError::InvalidProofFormat {
+                reason: format!(
+                    "IC length {} does not match nPublic + 1 ({})",
+                    data.ic.len(),
+                    n_public + 1
+                ),
+            });
+        }
+    }
+
+    // Reuse the same decimal-string-coordinate parsing as `VerifyingKeyJson` (see
+    // `crate::proof`): the snarkjs vkey shape is identical once the verifier-specific
+    // `nPublic`/`vk_alphabeta_12` fields are set aside.
+    VerifyingKeyJson {
+        protocol: data.protocol.clone(),
+        curve: data.curve.clone(),
+        vk_alpha_1: data.vk_alpha_1.clone(),
+        vk_beta_2: data.vk_beta_2.clone(),
+        vk_gamma_2: data.vk_gamma_2.clone(),
+        vk_delta_2: data.vk_delta_2.clone(),
+        ic: data.ic.clone(),
+    }
+    .into_verifying_key()
+}
+
+/// Threshold/range/tier verification keys compiled into the binary, following the
+/// semaphore-rs "embed circuit spec" approach of `include_bytes!` plus a lazily-parsed
+/// cell, so targets like `wasm`/`python` can verify proofs without shipping a filesystem of
+/// JSON keys alongside the binary. Only active under the `embed-keys` feature; see
+/// [`ComplianceVerifier::embedded`] and [`ComplianceVerifier::load_verification_key`].
+///
+/// `include_bytes!` is compile-time, so `keys/{circuit}/verification_key.json` (see
+/// `keys/README.md`) must exist on disk *before* building with this feature - keep it out
+/// of any blanket `--all-features` build/CI step until those files are generated and
+/// checked in for real.
+#[cfg(feature = "embed-keys")]
+mod embedded {
+    use std::sync::OnceLock;
+
+    use super::{parse_verification_key, Bn254, Circuit, Result, VerificationKeyJson, VerifyingKey};
+
+    static THRESHOLD_VK_JSON: &[u8] =
+        include_bytes!("../keys/compliance_threshold/verification_key.json");
+    static RANGE_VK_JSON: &[u8] = include_bytes!("../keys/range_proof/verification_key.json");
+    static TIER_VK_JSON: &[u8] = include_bytes!("../keys/tier_membership/verification_key.json");
+
+    static THRESHOLD_VK: OnceLock<VerifyingKey<Bn254>> = OnceLock::new();
+    static RANGE_VK: OnceLock<VerifyingKey<Bn254>> = OnceLock::new();
+    static TIER_VK: OnceLock<VerifyingKey<Bn254>> = OnceLock::new();
+
+    /// Return `circuit`'s embedded verification key, parsing and caching it on first use.
+    /// Returns `None` for circuits with no embedded key (only threshold/range/tier ship
+    /// one), so callers can fall through to the on-disk path.
+    pub fn embedded_verification_key(circuit: &Circuit) -> Option<Result<VerifyingKey<Bn254>>> {
+        let (cell, bytes) = match circuit {
+            Circuit::Threshold => (&THRESHOLD_VK, THRESHOLD_VK_JSON),
+            Circuit::Range => (&RANGE_VK, RANGE_VK_JSON),
+            Circuit::Tier => (&TIER_VK, TIER_VK_JSON),
+            _ => return None,
+        };
+
+        if let Some(vk) = cell.get() {
+            return Some(Ok(vk.clone()));
+        }
+
+        let vk = match serde_json::from_slice::<VerificationKeyJson>(bytes) {
+            Ok(data) => match parse_verification_key(&data) {
+                Ok(vk) => vk,
+                Err(e) => return Some(Err(e)),
+            },
+            Err(e) => return Some(Err(e.into())),
+        };
+
+        // Another thread may have won the race to parse first; either way the cell now
+        // holds a valid key, so read it back rather than trusting our own `vk`.
+        let vk = cell.get_or_init(|| vk);
+        Some(Ok(vk.clone()))
+    }
+}
+
+/// Verifies a single aggregated Groth16 proof that folds several compliance proofs'
+/// public signals into one Poseidon commitment, so a relying party checks one proof and
+/// one verifying key instead of one per inner proof. See
+/// [`crate::circuits::AggregationCircuit`] for the in-circuit commitment check and
+/// [`crate::prover::ComplianceProver::prove_aggregation`] for the matching prover hook.
+pub struct AggregationVerifier {
+    /// Base path to circuit build directory
+    build_dir: String,
+    /// Cached aggregation verification key
+    aggregation_vk: Option<VerifyingKey<Bn254>>,
+}
+
+impl AggregationVerifier {
+    /// Create a new aggregation verifier
+    pub fn new(build_dir: impl AsRef<str>) -> Result<Self> {
+        let build_dir = build_dir.as_ref().to_string();
+
+        if !Path::new(&build_dir).exists() {
+            return Err(ProverError::CircuitNotFound { path: build_dir });
+        }
+
+        Ok(Self {
+            build_dir,
+            aggregation_vk: None,
+        })
+    }
+
+    /// Verify a batch of proofs folded into a single aggregate.
+    ///
+    /// `proofs` must hold the aggregate proof last (tagged with
+    /// [`Circuit::Aggregation`]'s name), preceded by the one or more inner compliance
+    /// proofs it folds together - mixing circuit types (threshold, range, tier, ...) is
+    /// fine, since only their public signals are folded.
+    ///
+    /// The aggregate circuit only proves knowledge of a preimage to the Poseidon
+    /// commitment over the inner proofs' (already-public) signals - it says nothing about
+    /// whether those inner proofs are themselves valid Groth16 proofs. So before trusting
+    /// the commitment, this verifies every inner proof against its own circuit's
+    /// verifying key (looked up from its `circuit` tag, the same way
+    /// [`ComplianceVerifier`] would), exactly as a caller folding externally-verified
+    /// proofs would have already done - this just stops that precondition from being
+    /// silently unenforced. Only then does it recompute the expected commitment and check
+    /// it matches the aggregate's sole public input, before verifying the aggregate proof
+    /// itself against the aggregation verifying key. Tampering with any inner proof's
+    /// public inputs changes the expected commitment, so it fails this check even though
+    /// the aggregate proof itself is untouched.
+    #[instrument(skip(self, proofs), fields(count = proofs.len()))]
+    pub fn verify_batch(&mut self, proofs: &[ProofWithPublicInputs]) -> Result<bool> {
+        let (aggregate, inner) = proofs.split_last().ok_or_else(|| ProverError::InvalidProofFormat {
+            reason: "aggregation batch must contain the aggregate proof plus at least one inner proof".into(),
+        })?;
+
+        if aggregate.circuit != Circuit::Aggregation.file_name() {
+            return Err(ProverError::InvalidProofFormat {
+                reason: format!("expected aggregate proof last, got {}", aggregate.circuit),
+            });
+        }
+        if inner.is_empty() {
+            return Err(ProverError::InvalidProofFormat {
+                reason: "aggregation batch has no inner proofs to fold".into(),
+            });
+        }
+
+        for proof in inner {
+            let circuit = Circuit::from_file_name(&proof.circuit).ok_or_else(|| ProverError::InvalidProofFormat {
+                reason: format!("unknown circuit tag on inner proof: {}", proof.circuit),
+            })?;
+            let vk = load_verification_key(&self.build_dir, &circuit)?;
+            let is_valid = Groth16::<Bn254>::verify(&vk, &proof.public_inputs, &proof.proof.inner)
+                .map_err(|e| ProverError::VerificationFailed {
+                    reason: e.to_string(),
+                })?;
+            if !is_valid {
+                info!("Inner {} proof failed its own verification", circuit.file_name());
+                return Ok(false);
+            }
+        }
+
+        let expected_commitment = match aggregate_commitment(inner) {
+            Some(commitment) => commitment,
+            None => return Ok(false),
+        };
+
+        if aggregate.public_inputs != [expected_commitment] {
+            return Ok(false);
+        }
+
+        if self.aggregation_vk.is_none() {
+            self.aggregation_vk = Some(load_verification_key(&self.build_dir, &Circuit::Aggregation)?);
+        }
+        let vk = self.aggregation_vk.as_ref().expect("just populated above");
+
+        info!("Verifying aggregation proof over {} inner proofs", inner.len());
+        let is_valid = Groth16::<Bn254>::verify(vk, &aggregate.public_inputs, &aggregate.proof.inner)
+            .map_err(|e| ProverError::VerificationFailed {
+                reason: e.to_string(),
+            })?;
+
+        Ok(is_valid)
+    }
+}
+
+/// Fold `inner`'s flattened, zero-padded public signals into the Poseidon commitment an
+/// aggregated proof's sole public input must equal. Returns `None` if the flattened
+/// signals exceed [`crate::circuits::MAX_AGGREGATE_SIGNALS`].
+pub fn aggregate_commitment(inner: &[ProofWithPublicInputs]) -> Option<Fr> {
+    let flattened: Vec<Fr> = inner.iter().flat_map(|p| p.public_inputs.iter().copied()).collect();
+    let padded = pad_aggregate_signals(&flattened)?;
+    Some(PoseidonHasher::new().hash(&padded))
+}
+
 /// Verify a threshold proof (convenience function)
 pub fn verify_compliance_threshold(
     build_dir: &str,
@@ -169,3 +554,487 @@ pub fn verify_compliance_threshold(
     verifier.verify_threshold(proof)
 }
 
+/// Core random-linear-combination batch check shared by [`verify_batch`],
+/// [`verify_batch_strict`], and [`ComplianceVerifier::verify_many`].
+///
+/// Instead of `N` independent Groth16 checks (`3N` fixed-base pairings against
+/// `alpha/beta`, `gamma`, `delta`), this samples fresh non-zero random scalars `r_i` and
+/// folds the fixed pairing terms across all proofs: `e(alpha,beta)` becomes a single scalar
+/// exponent `sum(r_i)`, the gamma term folds into `e(sum(r_i * vk_x_i), gamma)`, and the
+/// delta term folds into `e(sum(r_i * C_i), delta)`. Each proof's `e(A_i, B_i)` still has a
+/// distinct `B_i`, so it contributes its own pairing input, but folding the rest cuts the
+/// fixed-base pairings from `3N` to `3`. The verifying key is used as-is; it is not
+/// converted to a `PreparedVerifyingKey`. The random `r_i` make an invalid proof slip
+/// through only with negligible probability.
+///
+/// Returns `true` only if every proof in `proofs` is valid; an empty batch is trivially
+/// valid. Does not identify which proof failed - see [`verify_batch`]/[`verify_many`] for
+/// that.
+fn randomized_batch_check(vk: &VerifyingKey<Bn254>, proofs: &[ProofWithPublicInputs]) -> Result<bool> {
+    if proofs.is_empty() {
+        return Ok(true);
+    }
+
+    let mut rng = thread_rng();
+
+    // Random non-zero scalars binding each proof into the batch check.
+    let scalars: Vec<Fr> = (0..proofs.len())
+        .map(|_| loop {
+            let r = Fr::rand(&mut rng);
+            if !r.is_zero() {
+                return r;
+            }
+        })
+        .collect();
+
+    let mut sum_r = Fr::zero();
+    let mut vk_x_acc = <Bn254 as Pairing>::G1::zero();
+    let mut c_acc = <Bn254 as Pairing>::G1::zero();
+    let mut scaled_a = Vec::with_capacity(proofs.len());
+    let mut b_points = Vec::with_capacity(proofs.len());
+
+    for (proof, &r) in proofs.iter().zip(scalars.iter()) {
+        let vk_x = ComplianceVerifier::compute_vk_x(vk, &proof.public_inputs)?;
+
+        sum_r += r;
+        vk_x_acc += vk_x * r;
+        c_acc += proof.proof.inner.c * r;
+
+        scaled_a.push((proof.proof.inner.a * r).into_affine());
+        b_points.push(proof.proof.inner.b);
+    }
+
+    // Fold the gamma/delta contributions in as two extra "proofs" with the negated
+    // accumulated points, so a single multi-pairing covers the whole batch.
+    let mut lhs_g1 = scaled_a;
+    lhs_g1.push((-vk_x_acc).into_affine());
+    lhs_g1.push((-c_acc).into_affine());
+
+    let mut lhs_g2 = b_points;
+    lhs_g2.push(vk.gamma_g2);
+    lhs_g2.push(vk.delta_g2);
+
+    let lhs = Bn254::multi_pairing(lhs_g1, lhs_g2);
+    let alpha_beta = Bn254::pairing(vk.alpha_g1, vk.beta_g2);
+    let rhs = alpha_beta * sum_r;
+
+    Ok(lhs == rhs)
+}
+
+/// Batch-verify `N` proofs that share one verifying key but carry different public inputs.
+///
+/// Returns the indices of any proofs that fail verification. An empty vector means the
+/// whole batch is valid. On a batch failure this falls back to verifying each proof
+/// individually so the caller learns exactly which ones are bad. See
+/// [`randomized_batch_check`] for the batching technique.
+pub fn verify_batch(vk: &VerifyingKey<Bn254>, proofs: &[ProofWithPublicInputs]) -> Result<Vec<usize>> {
+    if randomized_batch_check(vk, proofs)? {
+        return Ok(Vec::new());
+    }
+
+    // The aggregate check failed; fall back to per-proof verification to localize exactly
+    // which proofs are invalid.
+    let mut failed = Vec::new();
+    for (i, proof) in proofs.iter().enumerate() {
+        let is_valid = Groth16::<Bn254>::verify(vk, &proof.public_inputs, &proof.proof.inner)
+            .map_err(|e| ProverError::VerificationFailed { reason: e.to_string() })?;
+        if !is_valid {
+            failed.push(i);
+        }
+    }
+    Ok(failed)
+}
+
+/// All-or-nothing batch verification: `true` only if every proof in `proofs` is valid.
+///
+/// Unlike [`verify_batch`], this never falls back to per-proof verification on failure -
+/// callers who only need a single pass/fail answer skip that extra work. See
+/// [`randomized_batch_check`] for the batching technique.
+pub fn verify_batch_strict(vk: &VerifyingKey<Bn254>, proofs: &[ProofWithPublicInputs]) -> Result<bool> {
+    randomized_batch_check(vk, proofs)
+}
+
+/// Verify a proof against a standalone snarkjs-style verifying key and proof JSON, without
+/// needing a [`ComplianceVerifier`] or a circuit build directory on disk.
+///
+/// This is the end-to-end counterpart to [`VerifyingKeyJson`]/[`ProofJson`]: callers who
+/// loaded both from `verification_key.json`/`proof.json` files (e.g. produced by snarkjs)
+/// can verify directly against the public inputs they already have.
+pub fn verify_json(
+    vk_json: &VerifyingKeyJson,
+    proof_json: &ProofJson,
+    public_inputs: &[Fr],
+) -> Result<bool> {
+    let vk = vk_json.clone().into_verifying_key()?;
+    let proof = proof_json.clone().into_proof()?;
+
+    Groth16::<Bn254>::verify(&vk, public_inputs, &proof.inner).map_err(|e| {
+        ProverError::VerificationFailed {
+            reason: e.to_string(),
+        }
+    })
+}
+
+#[cfg(test)]
+mod zkey_tests {
+    use super::*;
+
+    #[test]
+    fn test_from_zkey_missing_file_errors() {
+        let dir = std::env::temp_dir().join("civium-from-zkey-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut verifier = ComplianceVerifier::new(dir.to_str().unwrap()).unwrap();
+
+        let result = verifier.from_zkey(&Circuit::Threshold, "does_not_exist.zkey");
+
+        assert!(result.is_err());
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_from_zkey_rejects_circuit_without_cache_slot() {
+        let dir = std::env::temp_dir().join("civium-from-zkey-membership-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut verifier = ComplianceVerifier::new(dir.to_str().unwrap()).unwrap();
+
+        // Membership has no verification-key cache slot on `ComplianceVerifier`; the
+        // circuit is rejected before the file is ever opened, so the error is the
+        // cache-slot `InvalidProofFormat`, not the missing file's `Io` error.
+        let result = verifier.from_zkey(&Circuit::Membership, "does_not_exist.zkey");
+
+        assert!(matches!(result, Err(ProverError::InvalidProofFormat { .. })));
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
+
+#[cfg(test)]
+mod solidity_tests {
+    use super::*;
+    use ark_groth16::Groth16;
+    use ark_snark::SNARK;
+    use ark_std::rand::thread_rng;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use crate::circuits::ThresholdCircuit;
+
+    #[test]
+    fn test_export_evm_verifier_matches_in_memory_vk() {
+        let circuit = ThresholdCircuit::new(8000, Fr::from(123456789u64), 8500, Fr::from(987654321u64));
+        let mut rng = thread_rng();
+        let (_, vk) = Groth16::<Bn254>::circuit_specific_setup(circuit, &mut rng).unwrap();
+
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+        let build_dir = std::env::temp_dir().join(format!("civium-evm-verifier-test-{nanos}"));
+        std::fs::create_dir_all(build_dir.join(Circuit::Threshold.file_name())).unwrap();
+        let vk_json = VerifyingKeyJson::from_verifying_key(&vk).unwrap();
+        std::fs::write(
+            build_dir.join(Circuit::Threshold.file_name()).join("verification_key.json"),
+            serde_json::to_string(&vk_json).unwrap(),
+        )
+        .unwrap();
+
+        let verifier = ComplianceVerifier::new(build_dir.to_str().unwrap()).unwrap();
+        let out_path = build_dir.join("Verifier.sol");
+        verifier
+            .export_evm_verifier(&Circuit::Threshold, out_path.to_str().unwrap())
+            .unwrap();
+
+        let source = std::fs::read_to_string(&out_path).unwrap();
+        assert!(source.contains(&vk.alpha_g1.x.to_string()));
+        assert_eq!(source, generate_verifier_contract(&vk));
+
+        std::fs::remove_dir_all(&build_dir).ok();
+    }
+}
+
+#[cfg(test)]
+mod batch_tests {
+    use super::*;
+    use ark_groth16::Groth16;
+    use ark_snark::SNARK;
+    use ark_std::rand::thread_rng;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use crate::circuits::AggregationCircuit;
+
+    /// Set up a real Groth16 proving/verifying key pair and `count` proofs against distinct
+    /// public inputs, sharing one `ComplianceVerifier` build dir tagged as [`Circuit::Threshold`]
+    /// (the circuit identity doesn't matter here, only that the vk on disk matches the proofs).
+    fn setup_many(count: usize) -> (std::path::PathBuf, Vec<ProofWithPublicInputs>) {
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+        let build_dir = std::env::temp_dir().join(format!("civium-verify-many-test-{nanos}"));
+        std::fs::create_dir_all(build_dir.join(Circuit::Threshold.file_name())).unwrap();
+
+        let mut rng = thread_rng();
+        let setup_circuit = AggregationCircuit::new(&[]).unwrap();
+        let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(setup_circuit, &mut rng).unwrap();
+        let vk_json = VerifyingKeyJson::from_verifying_key(&vk).unwrap();
+        std::fs::write(
+            build_dir.join(Circuit::Threshold.file_name()).join("verification_key.json"),
+            serde_json::to_string(&vk_json).unwrap(),
+        )
+        .unwrap();
+
+        let proofs = (0..count)
+            .map(|i| {
+                let circuit = AggregationCircuit::new(&[Fr::from(i as u64)]).unwrap();
+                let commitment = circuit.commitment;
+                let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng).unwrap();
+                ProofWithPublicInputs::new(
+                    Proof::new(proof),
+                    vec![commitment],
+                    Circuit::Threshold.file_name().to_string(),
+                )
+            })
+            .collect();
+
+        (build_dir, proofs)
+    }
+
+    #[test]
+    fn test_verify_batch_strict_all_valid() {
+        let (build_dir, proofs) = setup_many(5);
+        let verifier = ComplianceVerifier::new(build_dir.to_str().unwrap()).unwrap();
+
+        assert!(verifier.verify_batch_strict(&Circuit::Threshold, &proofs).unwrap());
+
+        std::fs::remove_dir_all(&build_dir).ok();
+    }
+
+    #[test]
+    fn test_verify_batch_strict_rejects_tampered_proof() {
+        let (build_dir, mut proofs) = setup_many(5);
+        proofs[2].public_inputs[0] = Fr::from(9999u64);
+        let verifier = ComplianceVerifier::new(build_dir.to_str().unwrap()).unwrap();
+
+        assert!(!verifier.verify_batch_strict(&Circuit::Threshold, &proofs).unwrap());
+
+        std::fs::remove_dir_all(&build_dir).ok();
+    }
+
+    #[test]
+    fn test_verify_many_localizes_invalid_proof() {
+        let (build_dir, mut proofs) = setup_many(5);
+        proofs[3].public_inputs[0] = Fr::from(9999u64);
+        let verifier = ComplianceVerifier::new(build_dir.to_str().unwrap()).unwrap();
+
+        let results = verifier.verify_many(&Circuit::Threshold, &proofs).unwrap();
+
+        assert_eq!(results, vec![true, true, true, false, true]);
+
+        std::fs::remove_dir_all(&build_dir).ok();
+    }
+
+    #[test]
+    fn test_verify_many_all_valid() {
+        let (build_dir, proofs) = setup_many(4);
+        let verifier = ComplianceVerifier::new(build_dir.to_str().unwrap()).unwrap();
+
+        let results = verifier.verify_many(&Circuit::Threshold, &proofs).unwrap();
+
+        assert_eq!(results, vec![true; 4]);
+
+        std::fs::remove_dir_all(&build_dir).ok();
+    }
+}
+
+#[cfg(test)]
+mod aggregation_tests {
+    use super::*;
+    use ark_groth16::Groth16;
+    use ark_snark::SNARK;
+    use ark_std::rand::thread_rng;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use crate::circuits::{AggregationCircuit, RangeCircuit, ThresholdCircuit};
+
+    /// Write `vk` to `build_dir/{circuit.file_name()}/verification_key.json`, the same
+    /// on-disk shape `load_verification_key` reads - so `AggregationVerifier::verify_batch`'s
+    /// per-inner-proof check can find a real verifying key for `circuit`, not just the
+    /// aggregation one.
+    fn persist_vk(build_dir: &std::path::Path, circuit: &Circuit, vk: &VerifyingKey<Bn254>) {
+        let circuit_dir = build_dir.join(circuit.file_name());
+        std::fs::create_dir_all(&circuit_dir).unwrap();
+        let vk_json = VerifyingKeyJson::from_verifying_key(vk).unwrap();
+        std::fs::write(circuit_dir.join("verification_key.json"), serde_json::to_string(&vk_json).unwrap()).unwrap();
+    }
+
+    /// Build a real, individually-verifiable `ThresholdCircuit` proof and persist its
+    /// verifying key to `build_dir`, so it stands up to `verify_batch`'s own-proof check
+    /// the same way a genuinely pre-verified inner proof would.
+    fn threshold_inner_proof(
+        build_dir: &std::path::Path,
+        threshold: u64,
+        entity_hash: u64,
+        score: u64,
+        salt: u64,
+    ) -> ProofWithPublicInputs {
+        let circuit = ThresholdCircuit::new(threshold, Fr::from(entity_hash), score, Fr::from(salt));
+        let mut rng = thread_rng();
+        let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(circuit.clone(), &mut rng).unwrap();
+        persist_vk(build_dir, &Circuit::Threshold, &vk);
+
+        let public_inputs = vec![circuit.threshold, circuit.entity_hash, circuit.commitment];
+        let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng).unwrap();
+        ProofWithPublicInputs::new(Proof::new(proof), public_inputs, Circuit::Threshold.file_name().to_string())
+    }
+
+    /// Build a real, individually-verifiable `RangeCircuit` proof and persist its
+    /// verifying key to `build_dir`, mirroring [`threshold_inner_proof`].
+    fn range_inner_proof(
+        build_dir: &std::path::Path,
+        min_score: u64,
+        max_score: u64,
+        entity_hash: u64,
+        score: u64,
+        salt: u64,
+    ) -> ProofWithPublicInputs {
+        let circuit = RangeCircuit::new(min_score, max_score, Fr::from(entity_hash), score, Fr::from(salt));
+        let mut rng = thread_rng();
+        let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(circuit.clone(), &mut rng).unwrap();
+        persist_vk(build_dir, &Circuit::Range, &vk);
+
+        let public_inputs = vec![circuit.min_score, circuit.max_score, circuit.entity_hash, circuit.commitment];
+        let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng).unwrap();
+        ProofWithPublicInputs::new(Proof::new(proof), public_inputs, Circuit::Range.file_name().to_string())
+    }
+
+    /// Set up a temp `build_dir` with a real aggregation verifying key, a real threshold-
+    /// and range-proof verifying key, an aggregate proof over `inner`, and a mix of a
+    /// threshold- and a range-shaped inner proof.
+    fn setup_batch() -> (std::path::PathBuf, Vec<ProofWithPublicInputs>) {
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+        let build_dir = std::env::temp_dir().join(format!("civium-aggregation-test-{nanos}"));
+        std::fs::create_dir_all(build_dir.join(Circuit::Aggregation.file_name())).unwrap();
+
+        let mut rng = thread_rng();
+        let (agg_pk, agg_vk) =
+            Groth16::<Bn254>::circuit_specific_setup(AggregationCircuit::new(&[]).unwrap(), &mut rng).unwrap();
+        persist_vk(&build_dir, &Circuit::Aggregation, &agg_vk);
+
+        let threshold_proof = threshold_inner_proof(&build_dir, 8000, 123, 8500, 456);
+        let range_proof = range_inner_proof(&build_dir, 7000, 9000, 123, 8000, 789);
+        let inner = vec![threshold_proof, range_proof];
+
+        let commitment = aggregate_commitment(&inner).unwrap();
+        let agg_circuit = AggregationCircuit::new(
+            &inner.iter().flat_map(|p| p.public_inputs.iter().copied()).collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let agg_proof = Groth16::<Bn254>::prove(&agg_pk, agg_circuit, &mut rng).unwrap();
+        let aggregate = ProofWithPublicInputs::new(
+            Proof::new(agg_proof),
+            vec![commitment],
+            Circuit::Aggregation.file_name().to_string(),
+        );
+
+        let mut batch = inner;
+        batch.push(aggregate);
+        (build_dir, batch)
+    }
+
+    #[test]
+    fn test_verify_batch_mixed_circuits_succeeds() {
+        let (build_dir, batch) = setup_batch();
+
+        let mut verifier = AggregationVerifier::new(build_dir.to_str().unwrap()).unwrap();
+        assert!(verifier.verify_batch(&batch).unwrap());
+
+        std::fs::remove_dir_all(&build_dir).ok();
+    }
+
+    #[test]
+    fn test_verify_batch_tampered_inner_input_fails() {
+        let (build_dir, mut batch) = setup_batch();
+
+        // Tamper with the threshold proof's public `threshold` signal; the tampered
+        // proof no longer verifies against its own circuit's verifying key, so this is
+        // now caught before the commitment is even recomputed (see
+        // `test_verify_batch_tampered_aggregate_commitment_fails` for that check).
+        batch[0].public_inputs[0] = Fr::from(9999u64);
+
+        let mut verifier = AggregationVerifier::new(build_dir.to_str().unwrap()).unwrap();
+        assert!(!verifier.verify_batch(&batch).unwrap());
+
+        std::fs::remove_dir_all(&build_dir).ok();
+    }
+
+    #[test]
+    fn test_verify_batch_tampered_aggregate_commitment_fails() {
+        let (build_dir, mut batch) = setup_batch();
+
+        // Every inner proof is untouched and individually valid; only the aggregate's
+        // claimed commitment is tampered, so this exercises the commitment-equality check
+        // specifically, independent of the per-inner-proof verification above.
+        let last = batch.len() - 1;
+        batch[last].public_inputs[0] = Fr::from(9999u64);
+
+        let mut verifier = AggregationVerifier::new(build_dir.to_str().unwrap()).unwrap();
+        assert!(!verifier.verify_batch(&batch).unwrap());
+
+        std::fs::remove_dir_all(&build_dir).ok();
+    }
+
+    #[test]
+    fn test_verify_batch_rejects_forged_inner_proof() {
+        let (build_dir, mut batch) = setup_batch();
+
+        // Swap the threshold proof's Groth16 bytes for the range proof's - the public
+        // inputs (and thus the recomputed commitment) are left alone, but the substituted
+        // proof no longer verifies against the threshold verifying key.
+        let range_proof_bytes = batch[1].proof.clone();
+        batch[0].proof = range_proof_bytes;
+
+        let mut verifier = AggregationVerifier::new(build_dir.to_str().unwrap()).unwrap();
+        assert!(!verifier.verify_batch(&batch).unwrap());
+
+        std::fs::remove_dir_all(&build_dir).ok();
+    }
+
+    #[test]
+    fn test_verify_batch_rejects_missing_aggregate_tag() {
+        let (build_dir, mut batch) = setup_batch();
+
+        // Drop the aggregate proof, leaving only inner proofs.
+        batch.pop();
+
+        let mut verifier = AggregationVerifier::new(build_dir.to_str().unwrap()).unwrap();
+        assert!(verifier.verify_batch(&batch).is_err());
+
+        std::fs::remove_dir_all(&build_dir).ok();
+    }
+
+    /// Round-trips `ComplianceProver::generate_keys`' persisted output straight into
+    /// `AggregationVerifier`, rather than hand-writing `verification_key.json` like
+    /// `setup_batch` does above. The aggregation circuit is the only one whose setup
+    /// doesn't need a circom `.wasm`/`.r1cs` pair on disk (see the comment inside
+    /// `generate_keys`), so it's the one circuit this round trip can exercise without fixture
+    /// files - but `persist_keys` and `load_verification_key` share the same on-disk
+    /// format for every circuit, so this covers the threshold/range/tier path too.
+    #[test]
+    fn test_generate_keys_round_trips_into_aggregation_verifier() {
+        use crate::prover::{Circuit as ProverCircuit, ComplianceProver};
+
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+        let build_dir = std::env::temp_dir().join(format!("civium-generate-keys-round-trip-{nanos}"));
+        std::fs::create_dir_all(&build_dir).unwrap();
+
+        let mut prover = ComplianceProver::new(build_dir.to_str().unwrap()).unwrap();
+        prover.generate_keys(&ProverCircuit::Aggregation).unwrap();
+
+        let inner = vec![threshold_inner_proof(&build_dir, 8000, 123, 8500, 456)];
+        let aggregate = prover.prove_aggregation(&inner).unwrap();
+
+        let mut batch = inner;
+        batch.push(aggregate);
+
+        // Verifies the aggregate proof against the verifying key `generate_keys` persisted
+        // to `build_dir` - this is the exact `CircuitNotFound` failure the mismatched
+        // `persist_keys`/`load_verification_key` formats used to cause.
+        let mut verifier = AggregationVerifier::new(build_dir.to_str().unwrap()).unwrap();
+        assert!(verifier.verify_batch(&batch).unwrap());
+
+        std::fs::remove_dir_all(&build_dir).ok();
+    }
+}
+